@@ -171,6 +171,39 @@ fn bench(c: &mut Criterion) {
     group.finish();
 }
 
+// 4x4 matrices have no column padding and are written with a single memcpy,
+// 3x3 ones have 4 bytes of padding after each column and are written column by column
+fn bench_matrices(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Matrices");
+
+    const COUNT: usize = 16 * 1024;
+
+    let mat4 = mint::ColumnMatrix4::from([[1.0_f32; 4]; 4]);
+    let mat3 = mint::ColumnMatrix3::from([[1.0_f32; 3]; 3]);
+    assert!(<mint::ColumnMatrix4<f32>>::METADATA.is_pod());
+    assert!(!<mint::ColumnMatrix3<f32>>::METADATA.is_pod());
+
+    group.throughput(Throughput::Bytes((COUNT * 64) as u64));
+    group.bench_function("mat4x4_write", |b| {
+        b.iter_batched_ref(
+            || (vec![mat4; COUNT], StorageBuffer::new(vec![0u8; COUNT * 64])),
+            |(src, dst)| dst.write(src).unwrap(),
+            criterion::BatchSize::LargeInput,
+        );
+    });
+
+    group.throughput(Throughput::Bytes((COUNT * 48) as u64));
+    group.bench_function("mat3x3_write", |b| {
+        b.iter_batched_ref(
+            || (vec![mat3; COUNT], StorageBuffer::new(vec![0u8; COUNT * 48])),
+            |(src, dst)| dst.write(src).unwrap(),
+            criterion::BatchSize::LargeInput,
+        );
+    });
+
+    group.finish();
+}
+
 fn manual_memcpy(src: &mut [u8], dst: &[u8]) {
     assert_eq!(src.len(), dst.len());
     #[allow(clippy::manual_memcpy)]
@@ -197,6 +230,6 @@ criterion_group! {
     name = benches;
     config = Criterion::default()
         .with_profiler(PProfProfiler::new(100, Output::Flamegraph(None)));
-    targets = bench
+    targets = bench, bench_matrices
 }
 criterion_main!(benches);
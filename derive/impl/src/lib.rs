@@ -1,12 +1,14 @@
-use proc_macro2::{Ident, Literal, Span, TokenStream};
-use quote::{quote, quote_spanned};
+use proc_macro2::{Ident, Literal, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::{
+    ext::IdentExt,
     parse::{Parse, ParseStream},
     parse_quote,
     punctuated::Punctuated,
     spanned::Spanned,
     token::Comma,
-    Data, DataStruct, DeriveInput, Error, Fields, FieldsNamed, GenericParam, LitInt, Path, Type,
+    Data, DataEnum, DataStruct, DeriveInput, Error, Fields, FieldsNamed, GenericParam, Generics,
+    LitInt, LitStr, Meta, Path, Type,
 };
 
 pub use syn;
@@ -14,7 +16,7 @@ pub use syn;
 #[macro_export]
 macro_rules! implement {
     ($path:expr) => {
-        #[proc_macro_derive(ShaderType, attributes(align, size))]
+        #[proc_macro_derive(ShaderType, attributes(align, size, shader))]
         pub fn derive_shader_type(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
             let input = $crate::syn::parse_macro_input!(input as $crate::syn::DeriveInput);
             let expanded = encase_derive_impl::derive_shader_type(input, &$path);
@@ -40,34 +42,66 @@ struct FieldData {
     pub field: syn::Field,
     pub size: Option<(u32, Span)>,
     pub align: Option<(u32, Span)>,
+    /// whether the struct uses the std140 layout
+    pub std140: bool,
+    /// whether the field is excluded from the shader representation
+    pub skip: bool,
 }
 
 impl FieldData {
     fn alignment(&self, root: &Path) -> TokenStream {
-        if let Some((alignment, _)) = self.align {
+        let ty = &self.field.ty;
+        let alignment = if let Some((alignment, _)) = self.align {
             let alignment = Literal::u64_suffixed(alignment as u64);
-            quote! {
+            // also checked here (not only in `align_check`) so that it fires for generic fields
+            quote! {{
+                let original_alignment = <#ty as #root::ShaderType>::METADATA.alignment().get();
+                #root::concat_assert!(
+                    original_alignment <= #alignment,
+                    "align attribute value must be at least ", original_alignment, " (field's type alignment)"
+                );
                 #root::AlignmentValue::new(#alignment)
-            }
+            }}
         } else {
-            let ty = &self.field.ty;
             quote! {
                 <#ty as #root::ShaderType>::METADATA.alignment()
             }
+        };
+        if self.std140 {
+            quote! {
+                match <#ty as #root::ShaderType>::METADATA.uniform_min_alignment() {
+                    ::core::option::Option::Some(min_alignment) => {
+                        #root::AlignmentValue::max([#alignment, min_alignment])
+                    }
+                    ::core::option::Option::None => #alignment,
+                }
+            }
+        } else {
+            alignment
         }
     }
 
     fn size(&self, root: &Path) -> TokenStream {
-        if let Some((size, _)) = self.size {
+        let ty = &self.field.ty;
+        let size = if let Some((size, _)) = self.size {
             let size = Literal::u64_suffixed(size as u64);
             quote! {
                 #size
             }
         } else {
-            let ty = &self.field.ty;
             quote! {
                 <#ty as #root::ShaderSize>::SHADER_SIZE.get()
             }
+        };
+        if self.std140 {
+            quote! {
+                match <#ty as #root::ShaderType>::METADATA.uniform_min_alignment() {
+                    ::core::option::Option::Some(min_alignment) => min_alignment.round_up(#size),
+                    ::core::option::Option::None => #size,
+                }
+            }
+        } else {
+            size
         }
     }
 
@@ -86,12 +120,26 @@ impl FieldData {
     }
 
     fn extra_padding(&self, root: &Path) -> Option<TokenStream> {
-        self.size.as_ref().map(|(size, _)| {
+        if self.size.is_none() && !self.std140 {
+            return None;
+        }
+        let ty = &self.field.ty;
+        // also checked here (not only in `size_check`) so that it fires for generic fields
+        let size_check = self.size.as_ref().map(|(size, _)| {
             let size = Literal::u64_suffixed(*size as u64);
-            let ty = &self.field.ty;
-            let original_size = quote! { <#ty as #root::ShaderSize>::SHADER_SIZE.get() };
-            quote!(#size.saturating_sub(#original_size))
-        })
+            quote! {
+                #root::concat_assert!(
+                    original_size <= #size,
+                    "size attribute value must be at least ", original_size, " (field's type size)"
+                );
+            }
+        });
+        let size = self.size(root);
+        Some(quote! {{
+            let original_size = <#ty as #root::ShaderSize>::SHADER_SIZE.get();
+            #size_check
+            #size - original_size
+        }})
     }
 
     fn ident(&self) -> &Ident {
@@ -130,6 +178,34 @@ impl Parse for StaticSizeAttr {
     }
 }
 
+enum StructAttr {
+    Std140,
+    GenerateReprC(Span),
+}
+
+impl Parse for StructAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let err = || {
+            syn::Error::new(
+                input.span(),
+                "expected `layout = \"std140\"` or `generate_repr_c`",
+            )
+        };
+        let key = input.parse::<Ident>().map_err(|_| err())?;
+        if key == "generate_repr_c" {
+            return Ok(StructAttr::GenerateReprC(key.span()));
+        }
+        if key != "layout" {
+            return Err(err());
+        }
+        input.parse::<syn::Token![=]>().map_err(|_| err())?;
+        match input.parse::<LitStr>() {
+            Ok(lit) if lit.value() == "std140" => Ok(StructAttr::Std140),
+            _ => Err(err()),
+        }
+    }
+}
+
 enum SizeAttr {
     Static(StaticSizeAttr),
     Runtime,
@@ -150,6 +226,8 @@ impl Parse for SizeAttr {
     }
 }
 
+const SIZE_ATTRS_EXCLUSIVE_ERR: &str = "`#[size(runtime)]` and `#[size(N)]` are mutually exclusive";
+
 struct Errors {
     inner: Option<Error>,
 }
@@ -175,18 +253,49 @@ impl Errors {
 pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
     let root = &parse_quote!(#root::private);
 
+    if let Data::Enum(data) = &input.data {
+        return derive_shader_type_for_enum(&input, data, root);
+    }
+
     let fields = match get_named_struct_fields(&input.data) {
         Ok(fields) => fields,
         Err(e) => return e.into_compile_error(),
     };
 
-    let last_field_index = fields.named.len() - 1;
+    // fields marked with `#[shader(skip)]` don't take part in the layout,
+    // the runtime-sized array (if any) has to be the last field that isn't skipped
+    let last_field_index = match fields.named.iter().rposition(|field| !is_skipped(field)) {
+        Some(i) => i,
+        None => {
+            return Error::new(Span::call_site(), "at least one field must not be skipped!")
+                .into_compile_error()
+        }
+    };
 
     let mut errors = Errors::new();
 
     let mut is_runtime_sized = false;
 
-    let field_data: Vec<_> = fields
+    let mut std140 = false;
+    let mut generate_repr_c = None;
+    for attr in &input.attrs {
+        if !attr.meta.path().is_ident("shader") {
+            continue;
+        }
+        match attr.parse_args_with(Punctuated::<StructAttr, Comma>::parse_terminated) {
+            Ok(inner) => {
+                for struct_attr in inner {
+                    match struct_attr {
+                        StructAttr::Std140 => std140 = true,
+                        StructAttr::GenerateReprC(span) => generate_repr_c = Some(span),
+                    }
+                }
+            }
+            Err(err) => errors.append(err),
+        }
+    }
+
+    let (field_data, skipped_fields): (Vec<_>, Vec<_>) = fields
         .named
         .iter()
         .enumerate()
@@ -195,35 +304,83 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
                 field: field.clone(),
                 size: None,
                 align: None,
+                std140,
+                skip: is_skipped(field),
             };
+            let mut has_runtime_size = false;
+
+            // both the bare (`#[align(X)]`) and grouped (`#[shader(align(X))]`) forms end up here
+            let mut metas = Vec::new();
             for attr in &field.attrs {
-                if !(attr.meta.path().is_ident("size") || attr.meta.path().is_ident("align")) {
-                    continue;
+                let path = attr.meta.path();
+                if path.is_ident("size") || path.is_ident("align") {
+                    metas.push(attr.meta.clone());
+                } else if path.is_ident("shader") {
+                    let res =
+                        attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated);
+                    match res {
+                        Ok(inner) => {
+                            for meta in inner {
+                                if meta.path().is_ident("size") || meta.path().is_ident("align") {
+                                    metas.push(meta);
+                                } else if meta.path().is_ident("skip") {
+                                    if let Err(err) = meta.require_path_only() {
+                                        errors.append(err);
+                                    }
+                                } else {
+                                    errors.append(Error::new(
+                                        meta.path().span(),
+                                        "unknown `shader` field attribute key, expected `align`, `size` or `skip`",
+                                    ));
+                                }
+                            }
+                        }
+                        Err(err) => errors.append(err),
+                    }
                 }
-                match attr.meta.require_list() {
+            }
+
+            if data.skip && !metas.is_empty() {
+                errors.append(Error::new(
+                    metas[0].path().span(),
+                    "`skip` can't be combined with `align` or `size`",
+                ));
+            }
+
+            for meta in &metas {
+                match meta.require_list() {
                     Ok(meta_list) => {
                         let span = meta_list.tokens.span();
                         if meta_list.path.is_ident("align") {
-                            let res = attr.parse_args::<AlignmentAttr>();
+                            let res = meta_list.parse_args::<AlignmentAttr>();
                             match res {
                                 Ok(val) => data.align = Some((val.0, span)),
                                 Err(err) => errors.append(err),
                             }
                         } else if meta_list.path.is_ident("size") {
                             let res = if i == last_field_index {
-                                attr.parse_args::<SizeAttr>().map(|val| match val {
-                                    SizeAttr::Runtime => {
-                                        is_runtime_sized = true;
-                                        None
-                                    }
-                                    SizeAttr::Static(size) => Some((size.0, span)),
-                                })
+                                meta_list.parse_args::<SizeAttr>()
                             } else {
-                                attr.parse_args::<StaticSizeAttr>()
-                                    .map(|val| Some((val.0, span)))
+                                meta_list
+                                    .parse_args::<StaticSizeAttr>()
+                                    .map(SizeAttr::Static)
                             };
+                            let is_size_set = has_runtime_size || data.size.is_some();
                             match res {
-                                Ok(val) => data.size = val,
+                                Ok(SizeAttr::Runtime) if data.size.is_some() => {
+                                    errors.append(Error::new(span, SIZE_ATTRS_EXCLUSIVE_ERR))
+                                }
+                                Ok(SizeAttr::Static(_)) if has_runtime_size => {
+                                    errors.append(Error::new(span, SIZE_ATTRS_EXCLUSIVE_ERR))
+                                }
+                                Ok(_) if is_size_set => {
+                                    errors.append(Error::new(span, "duplicate `size` attribute"))
+                                }
+                                Ok(SizeAttr::Runtime) => {
+                                    has_runtime_size = true;
+                                    is_runtime_sized = true;
+                                }
+                                Ok(SizeAttr::Static(size)) => data.size = Some((size.0, span)),
                                 Err(err) => errors.append(err),
                             }
                         }
@@ -233,35 +390,43 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
             }
             data
         })
-        .collect();
+        .partition(|data| !data.skip);
 
     let mut found = false;
     let size_hint: &Path = &parse_quote!(#root::ArrayLength);
     for field in &fields.named {
-        // TODO: rethink how to check type equality here
-        match &field.ty {
-            Type::Path(path)
-                if path.path.segments.last().unwrap().ident
-                    == size_hint.segments.last().unwrap().ident =>
-            {
-                if found {
+        if is_array_length(&field.ty, size_hint) {
+            if found {
+                let err = syn::Error::new(
+                    field.ty.span(),
+                    "only one field can use the `ArrayLength` type!",
+                );
+                errors.append(err)
+            } else {
+                if !is_runtime_sized {
                     let err = syn::Error::new(
-                        field.ty.span(),
-                        "only one field can use the `ArrayLength` type!",
-                    );
+                            field.ty.span(),
+                            "`ArrayLength` type can only be used within a struct containing a runtime-sized array marked as `#[size(runtime)]`!",
+                        );
                     errors.append(err)
-                } else {
-                    if !is_runtime_sized {
-                        let err = syn::Error::new(
-                                field.ty.span(),
-                                "`ArrayLength` type can only be used within a struct containing a runtime-sized array marked as `#[size(runtime)]`!",
-                            );
-                        errors.append(err)
-                    }
-                    found = true;
                 }
+                found = true;
             }
-            _ => {}
+        }
+    }
+
+    if let Some(span) = generate_repr_c {
+        if !input.generics.params.is_empty() {
+            errors.append(syn::Error::new(
+                span,
+                "`generate_repr_c` can't be used on structs with generic parameters",
+            ));
+        }
+        if is_runtime_sized {
+            errors.append(syn::Error::new(
+                span,
+                "`generate_repr_c` can't be used on structs containing a runtime-sized array",
+            ));
         }
     }
 
@@ -271,16 +436,8 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
 
     let nr_of_fields = &Literal::usize_suffixed(field_data.len());
 
-    let field_trait_constraints = generate_field_trait_constraints(
-        &input,
-        &field_data,
-        if is_runtime_sized {
-            quote!(#root::ShaderType + #root::RuntimeSizedArray)
-        } else {
-            quote!(#root::ShaderType + #root::ShaderSize)
-        },
-        quote!(#root::ShaderType + #root::ShaderSize),
-    );
+    let field_trait_constraints =
+        generate_field_trait_constraints(&input, &field_data, root, is_runtime_sized);
 
     let mut lifetimes = input.generics.clone();
     lifetimes.params = lifetimes
@@ -293,6 +450,7 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
         let (impl_generics, _, _) = lifetimes.split_for_impl();
         field_data
             .iter()
+            .filter(|data| !uses_type_or_const_params(&data.field.ty, &input.generics))
             .filter_map(|data| data.align.as_ref().map(|align| (&data.field.ty, align)))
             .map(move |(ty, (align, span))| {
                 let align = Literal::u64_suffixed(*align as u64);
@@ -317,6 +475,7 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
         let (impl_generics, _, _) = lifetimes.split_for_impl();
         field_data
             .iter()
+            .filter(|data| !uses_type_or_const_params(&data.field.ty, &input.generics))
             .filter_map(|data| data.size.as_ref().map(|size| (&data.field.ty, size)))
             .map(move |(ty, (size, span))| {
                 let size = Literal::u64_suffixed(*size as u64);
@@ -337,24 +496,77 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
             })
     };
 
+    // the std140 layout requires array strides to be multiples of 16 which can't be changed by the struct
+    let std140_array_fields: Vec<_> = field_data
+        .iter()
+        .filter(|data| data.std140)
+        .filter_map(|data| array_element_type(&data.field.ty).map(|el_ty| (data, el_ty)))
+        .collect();
+
+    let std140_stride_assert = |data: &FieldData, el_ty: &Type| {
+        let name = data.ident().unraw().to_string();
+        quote_spanned! {data.field.ty.span()=>
+            let stride = <#el_ty as #root::ShaderType>::METADATA
+                .alignment()
+                .round_up(<#el_ty as #root::ShaderSize>::SHADER_SIZE.get());
+            #root::concat_assert!(
+                stride % 16 == 0,
+                "array field `", #name, "` has a stride of ", stride,
+                " which is not a multiple of 16 (required by the std140 layout)"
+            );
+        }
+    };
+
+    let std140_array_check = {
+        let (impl_generics, _, _) = lifetimes.split_for_impl();
+        std140_array_fields
+            .iter()
+            .filter(|(data, _)| !uses_type_or_const_params(&data.field.ty, &input.generics))
+            .map(move |(data, el_ty)| {
+                let ty = &data.field.ty;
+                let stride_assert = std140_stride_assert(data, el_ty);
+                quote_spanned! {ty.span()=>
+                    const _: () = {
+                        #[track_caller]
+                        #[allow(clippy::extra_unused_lifetimes)]
+                        const fn check #impl_generics () {
+                            #stride_assert
+                        }
+                        check();
+                    };
+                }
+            })
+    };
+
+    // also checked in `METADATA` (not only in `std140_array_check`) so that it fires for generic fields
+    let std140_stride_check = std140_array_fields.iter().map(|(data, el_ty)| {
+        let stride_assert = std140_stride_assert(data, el_ty);
+        quote! {{
+            #stride_assert
+        }}
+    });
+    let std140_array_element_types = std140_array_fields.iter().map(|(_, el_ty)| el_ty);
+
     let uniform_check = field_data.iter().enumerate().map(|(i, data)| {
         let ty = &data.field.ty;
-        let ty_check = quote_spanned! {ty.span()=>
-            <#ty as #root::ShaderType>::UNIFORM_COMPAT_ASSERT()
-        };
         let ident = data.ident();
         let name = ident.to_string();
+        let ty_check = quote_spanned! {ty.span()=>
+            <#ty as #root::ShaderType>::UNIFORM_COMPAT_CHECK().map_err(|err| err.in_field(#name))?;
+        };
         let field_offset_check = quote_spanned! {ident.span()=>
             if let ::core::option::Option::Some(min_alignment) =
                 <#ty as #root::ShaderType>::METADATA.uniform_min_alignment()
             {
                 let offset = <Self as #root::ShaderType>::METADATA.offset(#i);
 
-                #root::concat_assert!(
-                    min_alignment.is_aligned(offset),
-                    "offset of field '", #name, "' must be a multiple of ", min_alignment.get(),
-                    " (current offset: ", offset, ")"
-                )
+                if !min_alignment.is_aligned(offset) {
+                    return ::core::result::Result::Err(#root::UniformCompatError::FieldOffset {
+                        field: #name,
+                        min_alignment: min_alignment.get(),
+                        offset,
+                    });
+                }
             }
         };
         let field_offset_diff = if i != 0 {
@@ -372,24 +584,57 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
                     let prev_size = <#prev_field_ty as #root::ShaderSize>::SHADER_SIZE.get();
                     let prev_size = min_alignment.round_up(prev_size);
 
-                    #root::concat_assert!(
-                        diff >= prev_size,
-                        "offset between fields '", #prev_ident_name, "' and '", #name, "' must be at least ",
-                        min_alignment.get(), " (currently: ", diff, ")"
-                    )
+                    if diff < prev_size {
+                        return ::core::result::Result::Err(#root::UniformCompatError::FieldOffsetDiff {
+                            prev_field: #prev_ident_name,
+                            field: #name,
+                            min_alignment: min_alignment.get(),
+                            diff,
+                        });
+                    }
                 }
             }
         } else {
-            quote! {()}
+            quote! {}
         };
         quote! {
-            #ty_check,
-            #field_offset_check,
+            #ty_check
+            #field_offset_check
             #field_offset_diff
         }
     });
 
+    // the in-memory representation only matches the shader one if the fields are laid out in order,
+    // are pod themselves and there is no padding (in both representations)
+    let is_repr_c = input.attrs.iter().any(|attr| {
+        attr.meta.path().is_ident("repr")
+            && attr
+                .parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
+                .map_or(false, |inner| {
+                    inner.iter().any(|meta| {
+                        meta.path().is_ident("C") || meta.path().is_ident("transparent")
+                    })
+                })
+    });
+    let is_pod = if is_repr_c && !is_runtime_sized {
+        let field_types = field_data.iter().map(|data| &data.field.ty);
+        quote! {{
+            let mut is_pod = ::core::mem::size_of::<Self>() as ::core::primitive::u64 == min_size.get();
+            #( is_pod = is_pod && <#field_types as #root::ShaderType>::METADATA.is_pod(); )*
+            let mut i = 0;
+            while i < #nr_of_fields {
+                is_pod = is_pod && extra.paddings[i] == 0;
+                i += 1;
+            }
+            is_pod
+        }}
+    } else {
+        quote!(false)
+    };
+
     let alignments = field_data.iter().map(|data| data.alignment(root));
+    // structs have a min alignment of 16 in the std140 layout
+    let std140_min_alignment = std140.then(|| quote!(#root::AlignmentValue::new(16),));
 
     let paddings = field_data.iter().enumerate().map(|(i, current)| {
         let is_first = i == 0;
@@ -438,6 +683,21 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
         out
     });
 
+    // catches regressions in the offset/padding computation above at compile time
+    let offset_check = field_data.windows(2).enumerate().map(|(i, pair)| {
+        let next_i = i + 1;
+        let size = pair[0].size(root);
+        let name = pair[0].ident().unraw().to_string();
+        let next_name = pair[1].ident().unraw().to_string();
+        quote! {
+            #root::concat_assert!(
+                extra.offsets[#i] + #size <= extra.offsets[#next_i],
+                "field `", #name, "` (offset ", extra.offsets[#i], ", size ", #size,
+                ") overlaps field `", #next_name, "` (offset ", extra.offsets[#next_i], ")"
+            );
+        }
+    });
+
     fn gen_body<'a>(
         field_data: &'a [FieldData],
         root: &'a Path,
@@ -507,7 +767,12 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
         },
     );
 
-    let field_idents = field_data.iter().map(|data| data.ident());
+    let skipped_field_idents = skipped_fields.iter().map(|data| data.ident());
+    let skipped_field_types = skipped_fields.iter().map(|data| &data.field.ty);
+    let field_idents = field_data
+        .iter()
+        .chain(&skipped_fields)
+        .map(|data| data.ident());
     let last_field = field_data.last().unwrap();
     let last_field_min_size = last_field.min_size(root);
     let last_field_ident = last_field.ident();
@@ -516,23 +781,37 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
     let field_types_2 = field_types.clone();
     let field_types_3 = field_types.clone();
     let field_types_4 = field_types.clone();
-    let all_other = field_types.clone().take(last_field_index);
+    let all_other = field_types.clone().take(field_data.len() - 1);
     let last_field_type = &last_field.field.ty;
 
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-    let set_contained_rt_sized_array_length = if is_runtime_sized {
-        quote! {
-            writer.ctx.rts_array_length = ::core::option::Option::Some(
-                #root::RuntimeSizedArray::len(&self.#last_field_ident)
-                as ::core::primitive::u32
-            );
-        }
-    } else {
-        TokenStream::new()
+    let repr_c_struct = match generate_repr_c {
+        Some(_) => generate_repr_c_struct(&input, &field_data, root),
+        None => TokenStream::new(),
     };
 
+    // the length of the runtime-sized array is handed to the `ArrayLength` field
+    // right before it's written (instead of once for the whole struct)
+    // so that nothing else can change it in between
+    let array_length_index = field_data
+        .iter()
+        .position(|data| is_array_length(&data.field.ty, size_hint));
+    let write_into_buffer_body = write_into_buffer_body.enumerate().map(|(i, body)| {
+        if is_runtime_sized && array_length_index == Some(i) {
+            quote! {
+                writer.ctx.rts_array_length = ::core::option::Option::Some(
+                    #root::RuntimeSizedArray::len(&self.#last_field_ident)
+                    as ::core::primitive::u32
+                );
+                #body
+            }
+        } else {
+            body
+        }
+    });
+
     let extra = match is_runtime_sized {
         true => quote! {
             impl #impl_generics #root::CalculateSizeFor for #name #ty_generics
@@ -545,6 +824,10 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
                     offset += <#last_field_type as #root::CalculateSizeFor>::calculate_size_for(nr_of_el).get();
                     #root::SizeValue::new(<Self as #root::ShaderType>::METADATA.alignment().round_up(offset)).0
                 }
+
+                fn header_size() -> ::core::primitive::u64 {
+                    <Self as #root::ShaderType>::METADATA.last_offset()
+                }
             }
         },
         false => quote! {
@@ -555,6 +838,19 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
         },
     };
 
+    let field_layouts = field_data.iter().enumerate().map(|(i, data)| {
+        let name = data.ident().unraw().to_string();
+        let ty = &data.field.ty;
+        quote! {
+            #root::FieldLayout {
+                name: #name,
+                offset: Self::METADATA.offset(#i),
+                padding: Self::METADATA.padding(#i),
+                layout: <#ty as #root::ShaderType>::type_layout(),
+            }
+        }
+    });
+
     // Note:
     // The unused HRTBs on WriteInto, ReadFrom and CreateFrom are there
     // to avoid #![feature(trivial_bounds)].
@@ -567,14 +863,19 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
 
         #( #size_check )*
 
+        #( #std140_array_check )*
+
         impl #impl_generics #root::ShaderType for #name #ty_generics #where_clause
         where
             #( #all_other: #root::ShaderType + #root::ShaderSize, )*
             #last_field_type: #root::ShaderType,
+            #( #std140_array_element_types: #root::ShaderType + #root::ShaderSize, )*
         {
             type ExtraMetadata = #root::StructMetadata<#nr_of_fields>;
             const METADATA: #root::Metadata<Self::ExtraMetadata> = {
-                let struct_alignment = #root::AlignmentValue::max([ #( #alignments, )* ]);
+                #( #std140_stride_check )*
+
+                let struct_alignment = #root::AlignmentValue::max([ #( #alignments, )* #std140_min_alignment ]);
 
                 let extra = {
                     let mut paddings = [0; #nr_of_fields];
@@ -584,30 +885,47 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
                     #root::StructMetadata { offsets, paddings }
                 };
 
+                #( #offset_check )*
+
                 let min_size = {
                     let mut offset = extra.offsets[#nr_of_fields - 1];
                     offset += #last_field_min_size;
                     #root::SizeValue::new(struct_alignment.round_up(offset))
                 };
 
+                let is_pod = #is_pod;
+
                 #root::Metadata {
                     alignment: struct_alignment,
                     has_uniform_min_alignment: true,
                     min_size,
-                    is_pod: false,
+                    is_pod,
                     extra,
                 }
             };
 
-            const UNIFORM_COMPAT_ASSERT: fn() = || #root::consume_zsts([
-                #( #uniform_check, )*
-            ]);
+            const UNIFORM_COMPAT_CHECK: fn() -> ::core::result::Result<(), #root::UniformCompatError> = || {
+                #( #uniform_check )*
+                ::core::result::Result::Ok(())
+            };
 
             fn size(&self) -> ::core::num::NonZeroU64 {
                 let mut offset = Self::METADATA.last_offset();
                 offset += #root::ShaderType::size(&self.#last_field_ident).get();
                 #root::SizeValue::new(Self::METADATA.alignment().round_up(offset)).0
             }
+
+            fn type_layout() -> #root::TypeLayout {
+                #root::TypeLayout {
+                    alignment: Self::METADATA.alignment().get(),
+                    size: Self::METADATA.min_size().get(),
+                    kind: #root::TypeLayoutKind::Struct {
+                        fields: ::std::vec::Vec::from([
+                            #( #field_layouts, )*
+                        ]),
+                    },
+                }
+            }
         }
 
         impl #impl_generics #root::WriteInto for #name #ty_generics
@@ -617,7 +935,6 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
         {
             #[inline]
             fn write_into<B: #root::BufferMut>(&self, writer: &mut #root::Writer<B>) {
-                #set_contained_rt_sized_array_length
                 #( #write_into_buffer_body )*
             }
         }
@@ -637,43 +954,490 @@ pub fn derive_shader_type(input: DeriveInput, root: &Path) -> TokenStream {
         where
             Self: #root::ShaderType<ExtraMetadata = #root::StructMetadata<#nr_of_fields>>,
             #( for<'__> #field_types_4: #root::CreateFrom, )*
+            #( for<'__> #skipped_field_types: ::core::default::Default, )*
         {
             #[inline]
             fn create_from<B: #root::BufferRef>(reader: &mut #root::Reader<B>) -> Self {
                 #( #create_from_buffer_body )*
+                #( let #skipped_field_idents = ::core::default::Default::default(); )*
 
                 #root::build_struct!(Self, #( #field_idents ),*)
             }
         }
 
         #extra
+
+        #repr_c_struct
     }
 }
 
+/// Enums are laid out as a tagged union (`struct { tag: u32, payload }`)
+///
+/// The tag is the discriminant of the variant, the payload is the variant's field (if any)
+/// and takes up as much space as the biggest one
+fn derive_shader_type_for_enum(input: &DeriveInput, data: &DataEnum, root: &Path) -> TokenStream {
+    if data.variants.is_empty() {
+        return Error::new(Span::call_site(), "enums must have at least one variant!")
+            .into_compile_error();
+    }
+
+    let mut errors = Errors::new();
+    for variant in &data.variants {
+        if variant.fields.len() > 1 {
+            errors.append(Error::new(
+                variant.fields.span(),
+                "enum variants can have at most one field (group multiple fields in a struct)!",
+            ));
+        }
+        // the payload is always placed at the same offset, there is nothing for the attributes to change
+        for attr in variant.fields.iter().flat_map(|field| &field.attrs) {
+            if ["align", "size", "shader"]
+                .iter()
+                .any(|name| attr.path().is_ident(name))
+            {
+                errors.append(Error::new(
+                    attr.span(),
+                    "attributes are not supported on the fields of enum variants!",
+                ));
+            }
+        }
+    }
+    if let Some(ts) = errors.into_compile_error() {
+        return ts;
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let payload_types: Vec<_> = data
+        .variants
+        .iter()
+        .filter_map(|variant| variant.fields.iter().next().map(|field| &field.ty))
+        .collect();
+    let payload_names: Vec<_> = data
+        .variants
+        .iter()
+        .filter(|variant| !variant.fields.is_empty())
+        .map(|variant| variant.ident.to_string())
+        .collect();
+
+    let payload_offset = quote! {
+        <Self as #root::ShaderType>::METADATA.alignment().round_up(4)
+    };
+    let size = quote! {
+        <Self as #root::ShaderType>::METADATA.min_size().get()
+    };
+
+    let patterns: Vec<_> = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let ident = &variant.ident;
+            match &variant.fields {
+                Fields::Named(fields) => {
+                    let field = fields.named[0].ident.as_ref().unwrap();
+                    quote!(Self::#ident { #field: value })
+                }
+                Fields::Unnamed(_) => quote!(Self::#ident(value)),
+                Fields::Unit => quote!(Self::#ident),
+            }
+        })
+        .collect();
+
+    // the tag of a variant is its discriminant (cast to a `u32`), following the same rules as `as` casts
+    // (variants without an explicit discriminant are one more than the previous variant)
+    let tags: Vec<_> = {
+        let mut base = None;
+        let mut increment = 0u32;
+        data.variants
+            .iter()
+            .map(|variant| {
+                if let Some((_, discriminant)) = &variant.discriminant {
+                    base = Some(discriminant);
+                    increment = 0;
+                }
+                let tag = match base {
+                    Some(discriminant) => {
+                        let increment = Literal::u32_suffixed(increment);
+                        quote!(((#discriminant) as ::core::primitive::u32).wrapping_add(#increment))
+                    }
+                    None => Literal::u32_suffixed(increment).into_token_stream(),
+                };
+                increment += 1;
+                tag
+            })
+            .collect()
+    };
+
+    let write_arms = data.variants.iter().zip(&patterns).zip(&tags).map(|((variant, pattern), tag)| {
+        match variant.fields.iter().next() {
+            Some(field) => {
+                let ty = &field.ty;
+                quote! {
+                    #pattern => {
+                        #root::WriteInto::write_into(&#tag, writer);
+                        writer.advance((#payload_offset - 4) as ::core::primitive::usize);
+                        #root::WriteInto::write_into(value, writer);
+                        writer.advance((#size - #payload_offset - <#ty as #root::ShaderSize>::SHADER_SIZE.get()) as ::core::primitive::usize);
+                    }
+                }
+            }
+            None => quote! {
+                #pattern => {
+                    #root::WriteInto::write_into(&#tag, writer);
+                    writer.advance((#size - 4) as ::core::primitive::usize);
+                }
+            },
+        }
+    });
+
+    let create_arms = data.variants.iter().zip(&tags).map(|(variant, tag)| {
+        let ident = &variant.ident;
+        match &variant.fields {
+            Fields::Unit => quote! {
+                tag if tag == #tag => {
+                    reader.advance((#size - #payload_offset) as ::core::primitive::usize);
+                    Self::#ident
+                }
+            },
+            fields => {
+                let field = fields.iter().next().unwrap();
+                let ty = &field.ty;
+                let construct = match &field.ident {
+                    Some(field_ident) => quote!(Self::#ident { #field_ident: value }),
+                    None => quote!(Self::#ident(value)),
+                };
+                quote! {
+                    tag if tag == #tag => {
+                        let value = #root::CreateFrom::create_from(reader);
+                        reader.advance((#size - #payload_offset - <#ty as #root::ShaderSize>::SHADER_SIZE.get()) as ::core::primitive::usize);
+                        #construct
+                    }
+                }
+            }
+        }
+    });
+
+    let name_str = name.to_string();
+
+    quote! {
+        impl #impl_generics #root::ShaderType for #name #ty_generics #where_clause
+        where
+            #( #payload_types: #root::ShaderType + #root::ShaderSize, )*
+        {
+            type ExtraMetadata = ();
+            const METADATA: #root::Metadata<Self::ExtraMetadata> = {
+                let alignment = #root::AlignmentValue::max([
+                    #root::AlignmentValue::new(4),
+                    #( <#payload_types as #root::ShaderType>::METADATA.alignment(), )*
+                ]);
+
+                let payload_sizes = [
+                    0,
+                    #( <#payload_types as #root::ShaderSize>::SHADER_SIZE.get(), )*
+                ];
+                let mut payload_size = 0;
+                let mut i = 0;
+                while i < payload_sizes.len() {
+                    if payload_sizes[i] > payload_size {
+                        payload_size = payload_sizes[i];
+                    }
+                    i += 1;
+                }
+
+                let min_size = #root::SizeValue::new(alignment.round_up(alignment.round_up(4) + payload_size));
+
+                #root::Metadata {
+                    alignment,
+                    has_uniform_min_alignment: true,
+                    min_size,
+                    is_pod: false,
+                    extra: (),
+                }
+            };
+
+            const UNIFORM_COMPAT_CHECK: fn() -> ::core::result::Result<(), #root::UniformCompatError> = || {
+                #(
+                    <#payload_types as #root::ShaderType>::UNIFORM_COMPAT_CHECK()?;
+                    if let ::core::option::Option::Some(min_alignment) =
+                        <#payload_types as #root::ShaderType>::METADATA.uniform_min_alignment()
+                    {
+                        let offset = #payload_offset;
+                        if !min_alignment.is_aligned(offset) {
+                            return ::core::result::Result::Err(#root::UniformCompatError::FieldOffset {
+                                field: #payload_names,
+                                min_alignment: min_alignment.get(),
+                                offset,
+                            });
+                        }
+                    }
+                )*
+                ::core::result::Result::Ok(())
+            };
+
+            fn type_layout() -> #root::TypeLayout {
+                let payload_offset = #payload_offset;
+                let size = #size;
+                let mut fields = ::std::vec::Vec::from([#root::FieldLayout {
+                    name: "tag",
+                    offset: 0,
+                    padding: payload_offset - 4,
+                    layout: <::core::primitive::u32 as #root::ShaderType>::type_layout(),
+                }]);
+                if size > payload_offset {
+                    fields.push(#root::FieldLayout {
+                        name: "payload",
+                        offset: payload_offset,
+                        padding: 0,
+                        layout: #root::TypeLayout {
+                            alignment: 4,
+                            size: size - payload_offset,
+                            kind: #root::TypeLayoutKind::Array {
+                                element: ::std::boxed::Box::new(
+                                    <::core::primitive::u32 as #root::ShaderType>::type_layout(),
+                                ),
+                                stride: 4,
+                                len: ::core::option::Option::Some((size - payload_offset) / 4),
+                            },
+                        },
+                    });
+                }
+                #root::TypeLayout {
+                    alignment: <Self as #root::ShaderType>::METADATA.alignment().get(),
+                    size,
+                    kind: #root::TypeLayoutKind::Struct { fields },
+                }
+            }
+        }
+
+        impl #impl_generics #root::ShaderSize for #name #ty_generics #where_clause
+        where
+            #( #payload_types: #root::ShaderSize, )*
+        {}
+
+        impl #impl_generics #root::WriteInto for #name #ty_generics #where_clause
+        where
+            Self: #root::ShaderType,
+            #( for<'__> #payload_types: #root::WriteInto + #root::ShaderSize, )*
+        {
+            #[inline]
+            fn write_into<B: #root::BufferMut>(&self, writer: &mut #root::Writer<B>) {
+                match self {
+                    #( #write_arms )*
+                }
+            }
+        }
+
+        impl #impl_generics #root::ReadFrom for #name #ty_generics #where_clause
+        where
+            Self: #root::CreateFrom,
+        {
+            #[inline]
+            fn read_from<B: #root::BufferRef>(&mut self, reader: &mut #root::Reader<B>) {
+                *self = #root::CreateFrom::create_from(reader);
+            }
+        }
+
+        impl #impl_generics #root::CreateFrom for #name #ty_generics #where_clause
+        where
+            Self: #root::ShaderType,
+            #( for<'__> #payload_types: #root::CreateFrom + #root::ShaderSize, )*
+        {
+            #[inline]
+            fn create_from<B: #root::BufferRef>(reader: &mut #root::Reader<B>) -> Self {
+                let tag: ::core::primitive::u32 = #root::CreateFrom::create_from(reader);
+                reader.advance((#payload_offset - 4) as ::core::primitive::usize);
+                match tag {
+                    #( #create_arms )*
+                    tag => ::core::panic!("invalid tag {} for enum `{}`", tag, #name_str),
+                }
+            }
+        }
+    }
+}
+
+/// Generates a `#[repr(C)]` sibling of the struct (named `{name}Gpu`) that has the same
+/// in-memory representation as the shader one by following each field with a padding field
+fn generate_repr_c_struct(
+    input: &DeriveInput,
+    field_data: &[FieldData],
+    root: &Path,
+) -> TokenStream {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let repr_c_name = format_ident!("{}Gpu", name);
+
+    let field_vis = field_data.iter().map(|data| &data.field.vis);
+    let field_idents = field_data.iter().map(|data| data.ident());
+    let field_idents_2 = field_idents.clone();
+    let field_idents_3 = field_idents.clone();
+    let field_types = field_data.iter().map(|data| &data.field.ty);
+    let padding_idents: Vec<_> = (0..field_data.len())
+        .map(|i| format_ident!("_pad{}", i))
+        .collect();
+    let padding_lens: Vec<_> = (0..field_data.len())
+        .map(|i| {
+            quote! {
+                { <#name as #root::ShaderType>::METADATA.padding(#i) as ::core::primitive::usize }
+            }
+        })
+        .collect();
+    let doc = format!("`#[repr(C)]` version of [`{name}`] with explicit padding, laid out like its shader representation");
+
+    quote! {
+        #[doc = #doc]
+        #[derive(::core::clone::Clone, ::core::marker::Copy)]
+        #[repr(C)]
+        #vis struct #repr_c_name {
+            #(
+                #field_vis #field_idents: #field_types,
+                #padding_idents: [::core::primitive::u8; #padding_lens],
+            )*
+        }
+
+        const _: () = {
+            let size = ::core::mem::size_of::<#repr_c_name>() as ::core::primitive::u64;
+            let shader_size = <#name as #root::ShaderType>::METADATA.min_size().get();
+            #root::concat_assert!(
+                size == shader_size,
+                "the size of the generated `#[repr(C)]` struct (", size, ") doesn't match the shader size (", shader_size, "), some field types have a different in-memory representation"
+            );
+        };
+
+        impl ::core::convert::From<#name> for #repr_c_name {
+            fn from(value: #name) -> Self {
+                let #name { #( #field_idents_2, )* .. } = value;
+                Self {
+                    #(
+                        #field_idents_3,
+                        #padding_idents: [0; #padding_lens],
+                    )*
+                }
+            }
+        }
+    }
+}
+
+/// Returns true if `ty` is the `ArrayLength` type
+fn is_array_length(ty: &Type, size_hint: &Path) -> bool {
+    // TODO: rethink how to check type equality here
+    match ty {
+        Type::Path(path) => {
+            path.path.segments.last().unwrap().ident == size_hint.segments.last().unwrap().ident
+        }
+        _ => false,
+    }
+}
+
+/// Returns true if `field` is marked with `#[shader(skip)]`
+fn is_skipped(field: &syn::Field) -> bool {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| attr.meta.path().is_ident("shader"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
+                .ok()
+        })
+        .any(|inner| inner.iter().any(|meta| meta.path().is_ident("skip")))
+}
+
+/// Returns true if `ty` mentions any of the type or const parameters in `generics`
+fn uses_type_or_const_params(ty: &Type, generics: &Generics) -> bool {
+    fn check(tokens: TokenStream, params: &[&Ident]) -> bool {
+        tokens.into_iter().any(|tt| match tt {
+            TokenTree::Ident(ident) => params.contains(&&ident),
+            TokenTree::Group(group) => check(group.stream(), params),
+            _ => false,
+        })
+    }
+
+    let params: Vec<_> = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(param) => Some(&param.ident),
+            GenericParam::Const(param) => Some(&param.ident),
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect();
+
+    check(ty.to_token_stream(), &params)
+}
+
 fn generate_field_trait_constraints<'a>(
     input: &'a DeriveInput,
     field_data: &'a [FieldData],
-    trait_for_last_field: TokenStream,
-    trait_for_all_other_fields: TokenStream,
+    root: &'a Path,
+    is_runtime_sized: bool,
 ) -> impl Iterator<Item = TokenStream> + 'a {
     let (impl_generics, _, where_clause) = input.generics.split_for_impl();
     field_data.iter().enumerate().map(move |(i, data)| {
         let ty = &data.field.ty;
 
-        let t = if i == field_data.len() - 1 {
-            &trait_for_last_field
+        // the names of the functions show up in the "required by a bound in" note of the error
+        let (size_check, size_trait) = if is_runtime_sized && i == field_data.len() - 1 {
+            (
+                quote!(field_marked_with_size_runtime_must_be_a_runtime_sized_array),
+                quote!(#root::RuntimeSizedArray),
+            )
         } else {
-            &trait_for_all_other_fields
+            (
+                quote!(field_must_have_a_fixed_size_unless_last_and_marked_with_size_runtime),
+                quote!(#root::ShaderSize),
+            )
         };
 
+        // structs containing a runtime-sized array don't have a fixed size and can't be array elements,
+        // checked separately so that the error points to the element type and names the field
+        let element_check = array_element_type(ty).map(|el_ty| {
+            let element_check = format_ident!(
+                "elements_of_array_field_{}_must_have_a_fixed_size_structs_containing_a_runtime_sized_array_cant_be_elements",
+                data.ident().unraw()
+            );
+            quote_spanned! {el_ty.span()=>
+                fn #element_check<T: ?::core::marker::Sized + #root::ShaderSize>() {}
+                #element_check::<#el_ty>();
+            }
+        });
+
         quote_spanned! {ty.span()=>
             const _: fn() = || {
                 #[allow(clippy::extra_unused_lifetimes, clippy::missing_const_for_fn, clippy::extra_unused_type_parameters)]
                 fn check #impl_generics () #where_clause {
-                    fn assert_impl<T: ?::core::marker::Sized + #t>() {}
-                    assert_impl::<#ty>();
+                    #element_check
+                    fn field_type_must_implement_shader_type<T: ?::core::marker::Sized + #root::ShaderType>() {}
+                    fn #size_check<T: ?::core::marker::Sized + #size_trait>() {}
+                    field_type_must_implement_shader_type::<#ty>();
+                    #size_check::<#ty>();
                 }
             };
         }
     })
 }
+
+/// Returns the element type of array types whose elements must have a fixed size
+/// (`[T; N]`, `[T]`, `Vec<T>`, `VecDeque<T>` and `LinkedList<T>`)
+fn array_element_type(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Array(array) => Some(&array.elem),
+        Type::Slice(slice) => Some(&slice.elem),
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last()?;
+            if !["Vec", "VecDeque", "LinkedList"]
+                .iter()
+                .any(|name| segment.ident == name)
+            {
+                return None;
+            }
+            match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => match args.args.first()? {
+                    syn::GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
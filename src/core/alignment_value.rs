@@ -14,6 +14,17 @@ impl AlignmentValue {
         Self(unsafe { NonZeroU64::new_unchecked(val) })
     }
 
+    /// Like [`Self::new`] but returns `None` (instead of panicking) if `val` is not a power of 2 (or is 0)
+    #[inline]
+    pub const fn try_new(val: u64) -> Option<Self> {
+        if val.is_power_of_two() {
+            // SAFETY: This is safe since 0 is not a power of 2
+            Some(Self(unsafe { NonZeroU64::new_unchecked(val) }))
+        } else {
+            None
+        }
+    }
+
     /// Returns an alignment that is the smallest power of two greater than the passed in `size`
     #[inline]
     pub const fn from_next_power_of_two_size(size: SizeValue) -> Self {
@@ -92,6 +103,14 @@ mod test {
         AlignmentValue::new(3);
     }
 
+    #[test]
+    fn try_new() {
+        assert_eq!(Some(AlignmentValue::new(16)), AlignmentValue::try_new(16));
+        assert_eq!(None, AlignmentValue::try_new(0));
+        assert_eq!(None, AlignmentValue::try_new(12));
+        assert_eq!(None, AlignmentValue::try_new(u64::MAX));
+    }
+
     #[test]
     fn from_next_power_of_two_size() {
         assert_eq!(
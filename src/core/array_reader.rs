@@ -0,0 +1,55 @@
+use core::marker::PhantomData;
+
+use super::{BufferRef, CreateFrom, Reader, ShaderSize};
+
+/// Lazily creates the elements of an array laid out in a buffer, one at a time
+///
+/// Returned by [`DynamicStorageBuffer::read_array`](super::DynamicStorageBuffer::read_array),
+/// useful for inspecting a few elements of a big buffer without creating all of them
+pub struct ArrayReader<'a, B, T> {
+    buffer: &'a B,
+    offset: usize,
+    len: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, B, T> ArrayReader<'a, B, T>
+where
+    B: BufferRef,
+    T: ShaderSize + CreateFrom,
+{
+    pub(crate) fn new(buffer: &'a B, offset: usize) -> Self {
+        let stride = T::array_stride() as usize;
+        let len = buffer.len().saturating_sub(offset) / stride;
+        Self {
+            buffer,
+            offset,
+            len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements that fit in the buffer
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Creates the element at `index` or returns `None` if it's out of bounds
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        let offset = self.offset + index * T::array_stride() as usize;
+        let mut reader = Reader::new::<T>(self.buffer, offset).ok()?;
+        Some(T::create_from(&mut reader))
+    }
+
+    /// Returns an iterator creating the elements in order
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = T> + '_ {
+        (0..self.len).map(move |index| self.get(index).unwrap())
+    }
+}
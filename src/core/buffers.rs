@@ -1,9 +1,11 @@
+use super::hashing::HashingBuffer;
 use super::{
-    AlignmentValue, BufferMut, BufferRef, CreateFrom, ReadFrom, Reader, Result, ShaderType,
-    WriteInto, Writer,
+    AlignmentValue, ArrayReader, BufferMut, BufferRef, CalculateSizeFor, CreateFrom, ReadFrom,
+    Reader, Result, ShaderSize, ShaderType, WriteInto, Writer,
 };
 
 /// Storage buffer wrapper facilitating RW operations
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct StorageBuffer<B> {
     inner: B,
 }
@@ -38,12 +40,92 @@ impl<B> AsMut<B> for StorageBuffer<B> {
 
 impl<B: BufferMut> StorageBuffer<B> {
     pub fn write<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + ShaderType + WriteInto,
+    {
+        self.write_at(0, value)
+    }
+
+    /// Like [`Self::write`] but starts writing at `offset` (in bytes) instead of the start of the buffer
+    ///
+    /// Useful for updating part of a larger buffer,
+    /// the caller is responsible for `offset` satisfying the alignment requirements of `T`
+    pub fn write_at<T>(&mut self, offset: u64, value: &T) -> Result<()>
+    where
+        T: ?Sized + ShaderType + WriteInto,
+    {
+        let mut writer = Writer::new(value, &mut self.inner, offset as usize)?;
+        value.write_into(&mut writer);
+        writer.finish()
+    }
+
+    /// Like [`Self::write`] but also zeroes all padding bytes
+    /// (instead of leaving what was previously in the buffer there)
+    pub fn write_zeroed_padding<T>(&mut self, value: &T) -> Result<()>
     where
         T: ?Sized + ShaderType + WriteInto,
     {
         let mut writer = Writer::new(value, &mut self.inner, 0)?;
+        writer.set_zero_padding(true);
         value.write_into(&mut writer);
-        Ok(())
+        writer.finish()
+    }
+
+    /// Like [`Self::write`] but also returns the 64-bit FNV-1a hash of the written bytes
+    ///
+    /// Padding bytes are skipped over (not written) so they are not part of the hash,
+    /// writing equal values results in equal hashes regardless of what the buffer contained before
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use encase::StorageBuffer;
+    /// let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    /// let hash = buffer.write_hashed(&[1u32, 2, 3]).unwrap();
+    ///
+    /// if buffer.write_hashed(&[1u32, 2, 3]).unwrap() == hash {
+    ///     // nothing changed, no need to upload the buffer again
+    /// }
+    /// ```
+    pub fn write_hashed<T>(&mut self, value: &T) -> Result<u64>
+    where
+        T: ?Sized + ShaderType + WriteInto,
+    {
+        let mut buffer = HashingBuffer::new(&mut self.inner);
+        let mut writer = Writer::new(value, &mut buffer, 0)?;
+        value.write_into(&mut writer);
+        writer.finish()?;
+        Ok(buffer.hash)
+    }
+
+    /// Writes the items of `iter` as a runtime-sized array
+    ///
+    /// The result is the same as writing the collected items (e.g. a `Vec<T>`)
+    /// but without having to allocate an intermediate collection
+    pub fn write_iter<T, I>(&mut self, iter: I) -> Result<()>
+    where
+        T: ShaderType + ShaderSize + WriteInto,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+        let len = iter.len();
+
+        let size = <[T]>::calculate_size_for(len as u64).get();
+        let mut writer = Writer::with_size(size, &mut self.inner, 0)?;
+
+        // `take` guards against iterators reporting a smaller length than the number of items they yield
+        for item in iter.take(len) {
+            item.write_into(&mut writer);
+            writer.advance(<[T]>::METADATA.el_padding() as usize);
+        }
+
+        // an empty array still takes up the space of one element
+        if len == 0 {
+            writer.write_zeros(<[T]>::METADATA.stride().get() as usize);
+        }
+
+        writer.finish()
     }
 }
 
@@ -52,7 +134,17 @@ impl<B: BufferRef> StorageBuffer<B> {
     where
         T: ?Sized + ShaderType + ReadFrom,
     {
-        let mut writer = Reader::new::<T>(&self.inner, 0)?;
+        self.read_at(0, value)
+    }
+
+    /// Like [`Self::read`] but starts reading at `offset` (in bytes) instead of the start of the buffer
+    ///
+    /// The caller is responsible for `offset` satisfying the alignment requirements of `T`
+    pub fn read_at<T>(&self, offset: u64, value: &mut T) -> Result<()>
+    where
+        T: ?Sized + ShaderType + ReadFrom,
+    {
+        let mut writer = Reader::new::<T>(&self.inner, offset as usize)?;
         value.read_from(&mut writer);
         Ok(())
     }
@@ -61,12 +153,122 @@ impl<B: BufferRef> StorageBuffer<B> {
     where
         T: ShaderType + CreateFrom,
     {
-        let mut writer = Reader::new::<T>(&self.inner, 0)?;
+        self.create_at(0)
+    }
+
+    /// Like [`Self::create`] but starts reading at `offset` (in bytes) instead of the start of the buffer
+    ///
+    /// The caller is responsible for `offset` satisfying the alignment requirements of `T`
+    pub fn create_at<T>(&self, offset: u64) -> Result<T>
+    where
+        T: ShaderType + CreateFrom,
+    {
+        let mut writer = Reader::new::<T>(&self.inner, offset as usize)?;
+        Ok(T::create_from(&mut writer))
+    }
+
+    /// Like [`Self::create`] but reads at most `max_bytes` bytes from the start of the buffer
+    ///
+    /// Runtime-sized arrays are created with as many elements as fit in the remaining budget
+    /// (instead of as many as fit in the buffer or as an [`ArrayLength`](crate::ArrayLength) field claims),
+    /// bounding the allocations made when reading data coming from untrusted sources
+    ///
+    /// Returns [`Error::BufferTooSmall`](super::Error::BufferTooSmall) if the min size of `T` doesn't fit in the budget
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use encase::StorageBuffer;
+    /// let buffer = StorageBuffer::new(vec![0u8; 1 << 20]);
+    ///
+    /// let created: Vec<u32> = buffer.create_bounded(64).unwrap();
+    /// assert_eq!(created.len(), 16);
+    /// ```
+    pub fn create_bounded<T>(&self, max_bytes: usize) -> Result<T>
+    where
+        T: ShaderType + CreateFrom,
+    {
+        let buffer = BoundedBuffer {
+            buffer: &self.inner,
+            len: self.inner.len().min(max_bytes),
+        };
+        let mut writer = Reader::new::<T>(buffer, 0)?;
         Ok(T::create_from(&mut writer))
     }
+
+    /// Reads the length written by an [`ArrayLength`](crate::ArrayLength) field at `field_offset` (in bytes)
+    /// without reading the rest of the struct (e.g. its runtime-sized array)
+    ///
+    /// The offset of the field can be obtained from the struct's metadata
+    /// (`T::METADATA.offset(i)` where `i` is the index of the field, skipped fields not included)
+    /// or from [`ShaderType::type_layout`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use encase::{ArrayLength, ShaderType, StorageBuffer};
+    /// #[derive(ShaderType)]
+    /// struct Positions {
+    ///     scale: f32,
+    ///     length: ArrayLength,
+    ///     #[size(runtime)]
+    ///     positions: Vec<mint::Point2<f32>>,
+    /// }
+    ///
+    /// let value = Positions {
+    ///     scale: 2.0,
+    ///     length: ArrayLength,
+    ///     positions: vec![[0.0, 1.0].into(); 3],
+    /// };
+    ///
+    /// let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    /// buffer.write(&value).unwrap();
+    ///
+    /// let offset = Positions::METADATA.offset(1);
+    /// assert_eq!(buffer.read_array_length(offset).unwrap(), 3);
+    /// ```
+    pub fn read_array_length(&self, field_offset: u64) -> Result<u32> {
+        self.create_at(field_offset)
+    }
+
+    /// Creates a single field of a struct found at `field_offset` (in bytes)
+    /// without creating the rest of the struct
+    ///
+    /// Like [`Self::create_at`] but restricted to fixed-size types,
+    /// see [`Self::read_array_length`] for how to get the offset of a field
+    pub fn read_field<T>(&self, field_offset: u64) -> Result<T>
+    where
+        T: ShaderSize + CreateFrom,
+    {
+        self.create_at(field_offset)
+    }
+}
+
+/// Exposes at most the first `len` bytes of `buffer`
+struct BoundedBuffer<'a, B> {
+    buffer: &'a B,
+    len: usize,
+}
+
+impl<B: BufferRef> BufferRef for BoundedBuffer<'_, B> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn read<const N: usize>(&self, offset: usize) -> &[u8; N] {
+        self.buffer.read(offset)
+    }
+
+    #[inline]
+    fn read_slice(&self, offset: usize, val: &mut [u8]) {
+        self.buffer.read_slice(offset, val)
+    }
 }
 
 /// Uniform buffer wrapper facilitating RW operations
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct UniformBuffer<B> {
     inner: StorageBuffer<B>,
 }
@@ -109,6 +311,39 @@ impl<B: BufferMut> UniformBuffer<B> {
         T::assert_uniform_compat();
         self.inner.write(value)
     }
+
+    /// Like [`Self::write`] but returns [`Error::UniformIncompatible`](super::Error::UniformIncompatible)
+    /// instead of panicking if `T` can't be used in uniform buffers
+    pub fn try_write<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + ShaderType + WriteInto,
+    {
+        T::check_uniform_compat()?;
+        self.inner.write(value)
+    }
+
+    /// Like [`Self::write`] but also zeroes all padding bytes
+    /// (instead of leaving what was previously in the buffer there)
+    pub fn write_zeroed_padding<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + ShaderType + WriteInto,
+    {
+        T::assert_uniform_compat();
+        self.inner.write_zeroed_padding(value)
+    }
+
+    /// Like [`Self::write`] but doesn't check that `T` can be used in uniform buffers
+    ///
+    /// **Warning:** the data will be laid out following the storage buffer rules,
+    /// if the buffer is bound as a uniform buffer, the shader will read garbage for types that fail the check
+    /// (e.g. a `[u32; 4]` has an array stride of 4 which is invalid in uniform buffers).
+    /// Only use this if the buffer is actually bound as a storage buffer.
+    pub fn write_unchecked<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + ShaderType + WriteInto,
+    {
+        self.inner.write(value)
+    }
 }
 
 impl<B: BufferRef> UniformBuffer<B> {
@@ -127,13 +362,42 @@ impl<B: BufferRef> UniformBuffer<B> {
         T::assert_uniform_compat();
         self.inner.create()
     }
+
+    /// Like [`Self::read`] but returns [`Error::UniformIncompatible`](super::Error::UniformIncompatible)
+    /// instead of panicking if `T` can't be used in uniform buffers
+    pub fn try_read<T>(&self, value: &mut T) -> Result<()>
+    where
+        T: ?Sized + ShaderType + ReadFrom,
+    {
+        T::check_uniform_compat()?;
+        self.inner.read(value)
+    }
+
+    /// Like [`Self::create`] but returns [`Error::UniformIncompatible`](super::Error::UniformIncompatible)
+    /// instead of panicking if `T` can't be used in uniform buffers
+    pub fn try_create<T>(&self) -> Result<T>
+    where
+        T: ShaderType + CreateFrom,
+    {
+        T::check_uniform_compat()?;
+        self.inner.create()
+    }
 }
 
 /// Dynamic storage buffer wrapper facilitating RW operations
+#[derive(Clone, PartialEq, Eq)]
 pub struct DynamicStorageBuffer<B> {
     inner: B,
     alignment: AlignmentValue,
     offset: usize,
+    /// end of the last written value
+    end: usize,
+    /// end of the last written value rounded up to the alignment
+    padded_end: usize,
+    /// furthest `padded_end` reached by any write
+    high_water_mark: usize,
+    /// offsets returned by `write` (if recording is enabled)
+    recorded_offsets: Option<Vec<u32>>,
 }
 
 impl<B> DynamicStorageBuffer<B> {
@@ -144,6 +408,8 @@ impl<B> DynamicStorageBuffer<B> {
     }
 
     /// Creates a new dynamic storage buffer wrapper with a given alignment.
+    ///
+    /// The alignment can be as large as 2^31 (offsets have to fit in a [`u32`] to be used as dynamic offsets).
     /// # Panics
     ///
     /// - if `alignment` is not a power of two.
@@ -156,9 +422,48 @@ impl<B> DynamicStorageBuffer<B> {
             inner: buffer,
             alignment: AlignmentValue::new(alignment),
             offset: 0,
+            end: 0,
+            padded_end: 0,
+            high_water_mark: 0,
+            recorded_offsets: None,
         }
     }
 
+    /// Creates a new dynamic storage buffer wrapper with an alignment of
+    /// [`min_storage_buffer_offset_alignment`](wgpu_types::Limits::min_storage_buffer_offset_alignment)
+    /// (the limit of the device the buffer will be used with).
+    /// # Panics
+    ///
+    /// - if the alignment is not a power of two.
+    /// - if the alignment is less than 32 (min alignment imposed by the WebGPU spec).
+    #[cfg(feature = "wgpu")]
+    pub const fn from_limits(buffer: B, limits: &wgpu_types::Limits) -> Self {
+        Self::new_with_alignment(buffer, limits.min_storage_buffer_offset_alignment as u64)
+    }
+
+    /// Returns the offsets at which values of the given `sizes` would be written
+    /// if they were written in order starting at the current offset (without writing anything)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use encase::DynamicStorageBuffer;
+    /// let buffer = DynamicStorageBuffer::new_with_alignment(Vec::<u8>::new(), 256);
+    /// let offsets = buffer.plan_offsets(&[4, 300, 16]);
+    /// assert_eq!(offsets, [0, 256, 768]);
+    /// ```
+    pub fn plan_offsets(&self, sizes: &[u64]) -> Vec<u64> {
+        let mut offset = self.offset as u64;
+        sizes
+            .iter()
+            .map(|size| {
+                let current = offset;
+                offset += self.alignment.round_up(*size);
+                current
+            })
+            .collect()
+    }
+
     pub fn set_offset(&mut self, offset: u64) {
         if !self.alignment.is_aligned(offset) {
             panic!(
@@ -171,17 +476,73 @@ impl<B> DynamicStorageBuffer<B> {
         self.offset = offset as usize;
     }
 
+    /// Enables or disables recording the offsets returned by [`Self::write`]
+    ///
+    /// While enabled, writes at offsets that don't fit in a [`u32`] return
+    /// [`Error::OffsetTooLarge`](super::Error::OffsetTooLarge) (without writing anything)
+    ///
+    /// Disabling it also discards the offsets recorded so far
+    pub fn set_record_offsets(&mut self, record: bool) {
+        match (record, &self.recorded_offsets) {
+            (true, None) => self.recorded_offsets = Some(Vec::new()),
+            (false, _) => self.recorded_offsets = None,
+            (true, Some(_)) => {}
+        }
+    }
+
+    /// Returns the offsets recorded since recording was enabled (or since the last [`Self::clear_offsets`])
+    ///
+    /// Can be passed directly as the dynamic offsets of `wgpu::RenderPass::set_bind_group`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use encase::DynamicStorageBuffer;
+    /// let mut buffer = DynamicStorageBuffer::new(Vec::<u8>::new());
+    /// buffer.set_record_offsets(true);
+    /// buffer.write(&1u32).unwrap();
+    /// buffer.write(&[2u32; 100]).unwrap();
+    /// buffer.write(&3u32).unwrap();
+    /// assert_eq!(buffer.offsets(), [0, 256, 768]);
+    /// ```
+    pub fn offsets(&self) -> &[u32] {
+        self.recorded_offsets.as_deref().unwrap_or(&[])
+    }
+
+    /// Discards the offsets recorded so far (recording stays enabled)
+    pub fn clear_offsets(&mut self) {
+        if let Some(offsets) = &mut self.recorded_offsets {
+            offsets.clear();
+        }
+    }
+
     pub fn into_inner(self) -> B {
         self.inner
     }
 }
 
+impl<B: core::fmt::Debug> core::fmt::Debug for DynamicStorageBuffer<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DynamicStorageBuffer")
+            .field("inner", &self.inner)
+            .field("alignment", &self.alignment.get())
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
 impl<B> From<B> for DynamicStorageBuffer<B> {
     fn from(buffer: B) -> Self {
         Self::new(buffer)
     }
 }
 
+impl<B: Default> Default for DynamicStorageBuffer<B> {
+    fn default() -> Self {
+        Self::new(B::default())
+    }
+}
+
 impl<B> AsRef<B> for DynamicStorageBuffer<B> {
     fn as_ref(&self) -> &B {
         &self.inner
@@ -201,13 +562,89 @@ impl<B: BufferMut> DynamicStorageBuffer<B> {
     {
         let offset = self.offset;
 
+        // checked before writing so that nothing is written if the offset can't be recorded
+        let recorded_offset = match &self.recorded_offsets {
+            Some(_) => Some(
+                u32::try_from(offset).map_err(|_| super::Error::OffsetTooLarge {
+                    offset: offset as u64,
+                })?,
+            ),
+            None => None,
+        };
+
         let mut writer = Writer::new(value, &mut self.inner, offset)?;
         value.write_into(&mut writer);
+        writer.finish()?;
 
         self.offset += self.alignment.round_up(value.size().get()) as usize;
+        self.end = offset + value.size().get() as usize;
+        self.padded_end = self.offset;
+        self.high_water_mark = self.high_water_mark.max(self.offset);
+
+        if let (Some(offsets), Some(recorded_offset)) =
+            (&mut self.recorded_offsets, recorded_offset)
+        {
+            offsets.push(recorded_offset);
+        }
 
         Ok(offset as u64)
     }
+
+    /// Like [`Self::write`] but writes `value` at `offset` (in bytes) instead of the current offset
+    /// (the current offset is then advanced past the written value)
+    ///
+    /// Returns [`Error::UnalignedOffset`](super::Error::UnalignedOffset) instead of panicking
+    /// (like [`Self::set_offset`] does) if `offset` is not a multiple of the alignment
+    pub fn write_at_offset<T>(&mut self, offset: u64, value: &T) -> Result<u64>
+    where
+        T: ?Sized + ShaderType + WriteInto,
+    {
+        if !self.alignment.is_aligned(offset) {
+            return Err(super::Error::UnalignedOffset {
+                offset,
+                alignment: self.alignment.get(),
+            });
+        }
+
+        let prev_offset = core::mem::replace(&mut self.offset, offset as usize);
+        let res = self.write(value);
+        if res.is_err() {
+            self.offset = prev_offset;
+        }
+        res
+    }
+
+    /// Zero-pads the last written value up to the next aligned offset
+    /// and enlarges the buffer up to the current offset
+    ///
+    /// After a [`Self::write`] the buffer ends right after the written value
+    /// while the offset is advanced to the next aligned boundary,
+    /// calling this makes the length of growable buffers (e.g. [`Vec`]s) match the offset.
+    /// Reused buffers that are longer are truncated to the furthest offset any write reached
+    /// (values written before moving the offset back with [`Self::set_offset`] are kept)
+    ///
+    /// Returns [`Error::BufferTooSmall`](super::Error::BufferTooSmall) if the buffer can't hold that many bytes
+    pub fn finish(&mut self) -> Result<()> {
+        let len = self.offset.max(self.padded_end);
+        if self.inner.try_enlarge(len).is_err() {
+            return Err(super::Error::BufferTooSmall {
+                expected: len as u64,
+                found: self.inner.capacity() as u64,
+            });
+        }
+
+        // growable buffers that were reused (or contain uninitialized bytes) might not be zeroed
+        const ZEROS: [u8; 64] = [0; 64];
+        let mut pos = self.end;
+        while pos < self.padded_end {
+            let len = (self.padded_end - pos).min(ZEROS.len());
+            self.inner.write_slice(pos, &ZEROS[..len]);
+            pos += len;
+        }
+
+        self.inner.truncate(self.offset.max(self.high_water_mark));
+        Ok(())
+    }
 }
 
 impl<B: BufferRef> DynamicStorageBuffer<B> {
@@ -234,9 +671,36 @@ impl<B: BufferRef> DynamicStorageBuffer<B> {
 
         Ok(value)
     }
+
+    /// Returns an [`ArrayReader`] over the array of `T`s starting at the current offset
+    /// (and spanning the rest of the buffer) which creates elements on demand
+    ///
+    /// The offset is not advanced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use encase::{DynamicStorageBuffer, StorageBuffer};
+    /// let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    /// buffer.write(&(0..100u32).collect::<Vec<_>>()).unwrap();
+    ///
+    /// let buffer = DynamicStorageBuffer::new(buffer.into_inner());
+    /// let array = buffer.read_array::<u32>();
+    ///
+    /// assert_eq!(array.len(), 100);
+    /// assert_eq!(array.get(42), Some(42));
+    /// assert_eq!(array.get(100), None);
+    /// ```
+    pub fn read_array<T>(&self) -> ArrayReader<'_, B, T>
+    where
+        T: ShaderSize + CreateFrom,
+    {
+        ArrayReader::new(&self.inner, self.offset)
+    }
 }
 
 /// Dynamic uniform buffer wrapper facilitating RW operations
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct DynamicUniformBuffer<B> {
     inner: DynamicStorageBuffer<B>,
 }
@@ -251,6 +715,8 @@ impl<B> DynamicUniformBuffer<B> {
     }
 
     /// Creates a new dynamic uniform buffer wrapper with a given alignment.
+    ///
+    /// The alignment can be as large as 2^31 (offsets have to fit in a [`u32`] to be used as dynamic offsets).
     /// # Panics
     ///
     /// - if `alignment` is not a power of two.
@@ -261,10 +727,37 @@ impl<B> DynamicUniformBuffer<B> {
         }
     }
 
+    /// Creates a new dynamic uniform buffer wrapper with an alignment of
+    /// [`min_uniform_buffer_offset_alignment`](wgpu_types::Limits::min_uniform_buffer_offset_alignment)
+    /// (the limit of the device the buffer will be used with).
+    /// # Panics
+    ///
+    /// - if the alignment is not a power of two.
+    /// - if the alignment is less than 32 (min alignment imposed by the WebGPU spec).
+    #[cfg(feature = "wgpu")]
+    pub const fn from_limits(buffer: B, limits: &wgpu_types::Limits) -> Self {
+        Self::new_with_alignment(buffer, limits.min_uniform_buffer_offset_alignment as u64)
+    }
+
     pub fn set_offset(&mut self, offset: u64) {
         self.inner.set_offset(offset);
     }
 
+    /// See [`DynamicStorageBuffer::set_record_offsets`]
+    pub fn set_record_offsets(&mut self, record: bool) {
+        self.inner.set_record_offsets(record);
+    }
+
+    /// See [`DynamicStorageBuffer::offsets`]
+    pub fn offsets(&self) -> &[u32] {
+        self.inner.offsets()
+    }
+
+    /// See [`DynamicStorageBuffer::clear_offsets`]
+    pub fn clear_offsets(&mut self) {
+        self.inner.clear_offsets();
+    }
+
     pub fn into_inner(self) -> B {
         self.inner.inner
     }
@@ -296,6 +789,30 @@ impl<B: BufferMut> DynamicUniformBuffer<B> {
         T::assert_uniform_compat();
         self.inner.write(value)
     }
+
+    /// Like [`Self::write`] but returns [`Error::UniformIncompatible`](super::Error::UniformIncompatible)
+    /// instead of panicking if `T` can't be used in uniform buffers
+    pub fn try_write<T>(&mut self, value: &T) -> Result<u64>
+    where
+        T: ?Sized + ShaderType + WriteInto,
+    {
+        T::check_uniform_compat()?;
+        self.inner.write(value)
+    }
+
+    /// See [`DynamicStorageBuffer::write_at_offset`]
+    pub fn write_at_offset<T>(&mut self, offset: u64, value: &T) -> Result<u64>
+    where
+        T: ?Sized + ShaderType + WriteInto,
+    {
+        T::assert_uniform_compat();
+        self.inner.write_at_offset(offset, value)
+    }
+
+    /// See [`DynamicStorageBuffer::finish`]
+    pub fn finish(&mut self) -> Result<()> {
+        self.inner.finish()
+    }
 }
 
 impl<B: BufferRef> DynamicUniformBuffer<B> {
@@ -314,4 +831,405 @@ impl<B: BufferRef> DynamicUniformBuffer<B> {
         T::assert_uniform_compat();
         self.inner.create()
     }
+
+    /// Like [`Self::read`] but returns [`Error::UniformIncompatible`](super::Error::UniformIncompatible)
+    /// instead of panicking if `T` can't be used in uniform buffers
+    pub fn try_read<T>(&mut self, value: &mut T) -> Result<()>
+    where
+        T: ?Sized + ShaderType + ReadFrom,
+    {
+        T::check_uniform_compat()?;
+        self.inner.read(value)
+    }
+
+    /// Like [`Self::create`] but returns [`Error::UniformIncompatible`](super::Error::UniformIncompatible)
+    /// instead of panicking if `T` can't be used in uniform buffers
+    pub fn try_create<T>(&mut self) -> Result<T>
+    where
+        T: ShaderType + CreateFrom,
+    {
+        T::check_uniform_compat()?;
+        self.inner.create()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DynamicStorageBuffer, DynamicUniformBuffer, StorageBuffer, UniformBuffer};
+    use core::mem::MaybeUninit;
+
+    #[test]
+    fn eq() {
+        let mut a = StorageBuffer::new(Vec::<u8>::new());
+        let mut b = StorageBuffer::new(Vec::<u8>::new());
+        a.write(&[1u32, 2]).unwrap();
+        b.write(&[1u32, 2]).unwrap();
+        assert_eq!(a, b);
+        b.write(&[1u32, 3]).unwrap();
+        assert_ne!(a, b);
+
+        let mut a = UniformBuffer::new(Vec::<u8>::new());
+        let mut b = UniformBuffer::new(Vec::<u8>::new());
+        a.write(&1u32).unwrap();
+        b.write(&1u32).unwrap();
+        assert_eq!(a, b);
+
+        let mut a = DynamicStorageBuffer::new(Vec::<u8>::new());
+        let mut b = DynamicStorageBuffer::new(Vec::<u8>::new());
+        a.write(&1u32).unwrap();
+        b.write(&1u32).unwrap();
+        assert_eq!(a, b);
+        b.write(&1u32).unwrap();
+        assert_ne!(a, b);
+
+        let mut a = DynamicUniformBuffer::new(Vec::<u8>::new());
+        let mut b = DynamicUniformBuffer::new(Vec::<u8>::new());
+        a.write(&1u32).unwrap();
+        b.write(&1u32).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn clone() {
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&[1u32, 2]).unwrap();
+        assert_eq!(buffer.clone(), buffer);
+
+        let mut buffer = DynamicStorageBuffer::new_with_alignment(Vec::<u8>::new(), 64);
+        buffer.write(&1u32).unwrap();
+        let mut cloned = buffer.clone();
+        assert_eq!(cloned, buffer);
+
+        // the offset and alignment carry over
+        assert_eq!(cloned.write(&2u32).unwrap(), 64);
+        assert_eq!(buffer.write(&2u32).unwrap(), 64);
+        assert_eq!(cloned, buffer);
+
+        let mut buffer = DynamicUniformBuffer::new_with_alignment(Vec::<u8>::new(), 32);
+        buffer.write(&1u32).unwrap();
+        let mut cloned = buffer.clone();
+        assert_eq!(cloned.write(&2u32).unwrap(), 32);
+        assert_eq!(cloned.as_ref().len(), 36);
+        assert_eq!(buffer.as_ref().len(), 4);
+    }
+
+    #[test]
+    fn write_zeroed_padding() {
+        // each column is padded with 4 bytes
+        let value = crate::NonSquareMat::<4, 3>([[1.0; 3]; 4]);
+
+        let mut expected = StorageBuffer::new(Vec::<u8>::new());
+        expected.write(&value).unwrap();
+
+        let mut buffer = StorageBuffer::new(vec![0xFF_u8; 64]);
+        buffer.write(&value).unwrap();
+        assert_ne!(buffer, expected);
+
+        let mut buffer = StorageBuffer::new(vec![0xFF_u8; 64]);
+        buffer.write_zeroed_padding(&value).unwrap();
+        assert_eq!(buffer, expected);
+
+        let mut expected = UniformBuffer::new(Vec::<u8>::new());
+        expected.write(&value).unwrap();
+
+        let mut buffer = UniformBuffer::new(vec![0xFF_u8; 64]);
+        buffer.write_zeroed_padding(&value).unwrap();
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn write_hashed() {
+        // each column is padded with 4 bytes
+        let value = crate::NonSquareMat::<4, 3>([[1.0; 3]; 4]);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        let hash = buffer.write_hashed(&value).unwrap();
+
+        let mut expected = StorageBuffer::new(Vec::<u8>::new());
+        expected.write(&value).unwrap();
+        assert_eq!(buffer, expected);
+
+        // the padding bytes contain garbage but aren't part of the hash
+        let mut other = StorageBuffer::new(vec![0xFF_u8; 64]);
+        assert_eq!(other.write_hashed(&value).unwrap(), hash);
+
+        let mut changed = value;
+        changed.0[2][1] = 2.0;
+        assert_ne!(buffer.write_hashed(&changed).unwrap(), hash);
+
+        let mut buffer = StorageBuffer::new([0u8; 4]);
+        assert!(buffer.write_hashed(&[1u32, 2]).is_err());
+    }
+
+    #[test]
+    fn write_unchecked() {
+        let mut buffer = UniformBuffer::new(Vec::<u8>::new());
+        assert!(buffer.try_write(&[1u32, 2]).is_err());
+
+        buffer.write_unchecked(&[1u32, 2]).unwrap();
+        assert_eq!(buffer.as_ref(), &[1, 0, 0, 0, 2, 0, 0, 0]);
+    }
+
+    #[test]
+    fn page_alignment() {
+        let mut buffer = DynamicStorageBuffer::new_with_alignment(Vec::<u8>::new(), 4096);
+        assert_eq!(buffer.write(&1u32).unwrap(), 0);
+        assert_eq!(buffer.write(&[2u32; 1025]).unwrap(), 4096);
+        assert_eq!(buffer.write(&3u32).unwrap(), 12288);
+        buffer.finish().unwrap();
+        assert_eq!(buffer.as_ref().len(), 16384);
+        assert_eq!(&buffer.as_ref()[12288..12292], &[3, 0, 0, 0]);
+
+        buffer.set_offset(12288);
+        assert_eq!(buffer.create::<u32>().unwrap(), 3);
+    }
+
+    #[test]
+    fn plan_offsets() {
+        let mut buffer = DynamicStorageBuffer::new_with_alignment(Vec::<u8>::new(), 64);
+        let planned = buffer.plan_offsets(&[4, 80, 64, 4]);
+        let offsets = [
+            buffer.write(&1u32).unwrap(),
+            buffer.write(&[1u32; 20]).unwrap(),
+            buffer.write(&[1u32; 16]).unwrap(),
+            buffer.write(&1u32).unwrap(),
+        ];
+        assert_eq!(planned, offsets);
+        assert_eq!(planned, [0, 64, 192, 256]);
+
+        // planning starts at the current offset
+        assert_eq!(buffer.plan_offsets(&[4, 4]), [320, 384]);
+
+        assert!(buffer.plan_offsets(&[]).is_empty());
+    }
+
+    #[test]
+    fn finish() {
+        let mut buffer = DynamicStorageBuffer::new_with_alignment(Vec::<u8>::new(), 64);
+        buffer.write(&[1u32; 3]).unwrap();
+        let offset = buffer.write(&[1u32; 3]).unwrap();
+        assert_eq!(offset, 64);
+        assert_eq!(buffer.as_ref().len(), 76);
+
+        buffer.finish().unwrap();
+        assert_eq!(buffer.as_ref().len(), 128);
+        assert!(buffer.as_ref()[76..].iter().all(|b| *b == 0));
+
+        let mut buffer = DynamicUniformBuffer::new(Vec::<u8>::new());
+        buffer.write(&1u32).unwrap();
+        buffer.finish().unwrap();
+        assert_eq!(buffer.as_ref().len(), 256);
+
+        let mut buffer = DynamicStorageBuffer::new_with_alignment([0u8; 40], 32);
+        buffer.write(&1u32).unwrap();
+        buffer.write(&1u32).unwrap();
+        assert!(buffer.finish().is_err());
+    }
+
+    #[test]
+    fn finish_reused() {
+        // stale bytes past the last value are zeroed and the rest is truncated
+        let mut buffer = DynamicStorageBuffer::new_with_alignment(vec![0xFF_u8; 256], 32);
+        buffer.write(&1u32).unwrap();
+        buffer.write(&2u32).unwrap();
+        assert_eq!(buffer.as_ref().len(), 256);
+
+        buffer.finish().unwrap();
+        assert_eq!(buffer.as_ref().len(), 64);
+        assert_eq!(&buffer.as_ref()[32..36], &[2, 0, 0, 0]);
+        assert!(buffer.as_ref()[36..].iter().all(|b| *b == 0));
+
+        let mut buffer =
+            DynamicStorageBuffer::new_with_alignment(Vec::<MaybeUninit<u8>>::new(), 32);
+        buffer.write(&1u32).unwrap();
+        buffer.finish().unwrap();
+        assert_eq!(buffer.as_ref().len(), 32);
+        // SAFETY: `finish` initialized the bytes after the written value
+        let padding: Vec<u8> = buffer.as_ref()[4..]
+            .iter()
+            .map(|b| unsafe { b.assume_init() })
+            .collect();
+        assert_eq!(padding, [0; 28]);
+    }
+
+    #[test]
+    fn finish_after_moving_back() {
+        let mut buffer = DynamicStorageBuffer::new_with_alignment(Vec::<u8>::new(), 256);
+        buffer.write(&1u32).unwrap();
+        buffer.write(&2u32).unwrap();
+
+        // rewriting the first value keeps the second one
+        buffer.set_offset(0);
+        buffer.write(&3u32).unwrap();
+        buffer.finish().unwrap();
+        assert_eq!(buffer.as_ref().len(), 512);
+        assert_eq!(&buffer.as_ref()[..4], &[3, 0, 0, 0]);
+        assert_eq!(&buffer.as_ref()[256..260], &[2, 0, 0, 0]);
+
+        // and so does reading (which only advances the offset)
+        buffer.set_offset(0);
+        assert_eq!(buffer.create::<u32>().unwrap(), 3);
+        buffer.finish().unwrap();
+        assert_eq!(buffer.as_ref().len(), 512);
+        assert_eq!(&buffer.as_ref()[256..260], &[2, 0, 0, 0]);
+    }
+
+    #[test]
+    fn write_at() {
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write_at(16, &[3u32, 4]).unwrap();
+        buffer.write_at(4, &2u32).unwrap();
+        assert_eq!(buffer.as_ref().len(), 24);
+
+        assert_eq!(buffer.create_at::<u32>(4).unwrap(), 2);
+        assert_eq!(buffer.create_at::<[u32; 2]>(16).unwrap(), [3, 4]);
+
+        let mut value = [0u32; 2];
+        buffer.read_at(16, &mut value).unwrap();
+        assert_eq!(value, [3, 4]);
+
+        let mut buffer = StorageBuffer::new([0u8; 8]);
+        buffer.write_at(0, &1u32).unwrap();
+        buffer.write_at(4, &2u32).unwrap();
+        assert_eq!(buffer.create::<[u32; 2]>().unwrap(), [1, 2]);
+        assert!(buffer.write_at(8, &3u32).is_err());
+        assert!(buffer.create_at::<u32>(6).is_err());
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn record_offsets_too_large() {
+        let mut buffer = DynamicStorageBuffer::new(Vec::<u8>::new());
+        buffer.set_record_offsets(true);
+        buffer.set_offset(1 << 32);
+
+        assert!(matches!(
+            buffer.write(&1u32),
+            Err(crate::internal::Error::OffsetTooLarge { offset }) if offset == 1 << 32
+        ));
+        assert!(buffer.offsets().is_empty());
+        assert!(buffer.as_ref().is_empty());
+    }
+
+    #[test]
+    fn write_at_offset() {
+        let mut buffer = DynamicStorageBuffer::new_with_alignment(Vec::<u8>::new(), 64);
+        assert_eq!(buffer.write_at_offset(128, &1u32).unwrap(), 128);
+        assert_eq!(buffer.write(&2u32).unwrap(), 192);
+        assert_eq!(buffer.as_ref().len(), 196);
+        assert_eq!(&buffer.as_ref()[128..132], &[1, 0, 0, 0]);
+
+        assert!(matches!(
+            buffer.write_at_offset(100, &3u32),
+            Err(crate::internal::Error::UnalignedOffset {
+                offset: 100,
+                alignment: 64
+            })
+        ));
+        // the current offset is left untouched on errors
+        assert_eq!(buffer.write(&3u32).unwrap(), 256);
+
+        let mut buffer = DynamicStorageBuffer::new_with_alignment([0u8; 64], 32);
+        assert!(buffer.write_at_offset(64, &1u32).is_err());
+        assert_eq!(buffer.write(&1u32).unwrap(), 0);
+
+        let mut buffer = DynamicUniformBuffer::new_with_alignment(Vec::<u8>::new(), 32);
+        assert_eq!(buffer.write_at_offset(32, &1u32).unwrap(), 32);
+        assert!(buffer.write_at_offset(48, &1u32).is_err());
+    }
+
+    #[test]
+    fn read_array() {
+        let items: Vec<[u32; 2]> = (0..1000).map(|i| [i, i * 2]).collect();
+
+        let mut buffer = DynamicStorageBuffer::new_with_alignment(Vec::<u8>::new(), 32);
+        buffer.write(&7u32).unwrap();
+        assert_eq!(buffer.write(&items).unwrap(), 32);
+        buffer.set_offset(32);
+
+        let array = buffer.read_array::<[u32; 2]>();
+        assert_eq!(array.len(), 1000);
+        assert_eq!(array.get(0), Some([0, 0]));
+        assert_eq!(array.get(417), Some([417, 834]));
+        assert_eq!(array.get(999), Some([999, 1998]));
+        assert_eq!(array.get(1000), None);
+        assert!(array.iter().eq(items.iter().copied()));
+
+        // the offset is not advanced
+        assert_eq!(buffer.create::<[u32; 2]>().unwrap(), [0, 0]);
+
+        let buffer = DynamicStorageBuffer::new_with_alignment(vec![0u8; 4], 32);
+        assert!(buffer.read_array::<[u32; 2]>().is_empty());
+    }
+
+    #[test]
+    fn recorded_offsets() {
+        let mut buffer = DynamicUniformBuffer::new(Vec::<u8>::new());
+        buffer.write(&1u32).unwrap();
+        assert!(buffer.offsets().is_empty());
+
+        buffer.set_record_offsets(true);
+        buffer.write(&2u32).unwrap();
+        buffer.write(&[[3u32; 4]; 20]).unwrap();
+        buffer.write(&4u32).unwrap();
+        assert_eq!(buffer.offsets(), [256, 512, 1024]);
+
+        buffer.clear_offsets();
+        buffer.write(&5u32).unwrap();
+        assert_eq!(buffer.offsets(), [1280]);
+
+        buffer.set_record_offsets(false);
+        buffer.write(&6u32).unwrap();
+        assert!(buffer.offsets().is_empty());
+    }
+
+    #[cfg(feature = "wgpu")]
+    #[test]
+    fn from_limits() {
+        let limits = wgpu_types::Limits {
+            min_storage_buffer_offset_alignment: 64,
+            min_uniform_buffer_offset_alignment: 128,
+            ..Default::default()
+        };
+
+        let mut buffer = DynamicStorageBuffer::from_limits(Vec::<u8>::new(), &limits);
+        assert_eq!(buffer.write(&1u32).unwrap(), 0);
+        assert_eq!(buffer.write(&2u32).unwrap(), 64);
+
+        let mut buffer = DynamicUniformBuffer::from_limits(Vec::<u8>::new(), &limits);
+        assert_eq!(buffer.write(&1u32).unwrap(), 0);
+        assert_eq!(buffer.write(&2u32).unwrap(), 128);
+    }
+
+    #[test]
+    fn default() {
+        let buffer = StorageBuffer::<Vec<u8>>::default();
+        assert!(buffer.as_ref().is_empty());
+
+        let buffer = UniformBuffer::<Vec<u8>>::default();
+        assert!(buffer.as_ref().is_empty());
+
+        let mut buffer = DynamicStorageBuffer::<Vec<u8>>::default();
+        assert!(buffer.as_ref().is_empty());
+        assert_eq!(buffer.write(&1u32).unwrap(), 0);
+        assert_eq!(buffer.write(&2u32).unwrap(), 256);
+
+        let mut buffer = DynamicUniformBuffer::<Vec<u8>>::default();
+        assert!(buffer.as_ref().is_empty());
+        assert_eq!(buffer.write(&1u32).unwrap(), 0);
+        assert_eq!(buffer.write(&2u32).unwrap(), 256);
+    }
+
+    #[test]
+    fn debug() {
+        let buffer = StorageBuffer::new([1u8, 2]);
+        assert_eq!(format!("{buffer:?}"), "StorageBuffer { inner: [1, 2] }");
+
+        let mut buffer = DynamicStorageBuffer::new_with_alignment(Vec::<u8>::new(), 32);
+        buffer.set_offset(64);
+        assert_eq!(
+            format!("{buffer:?}"),
+            "DynamicStorageBuffer { inner: [], alignment: 32, offset: 64 }"
+        );
+    }
 }
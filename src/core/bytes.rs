@@ -0,0 +1,40 @@
+use super::{CreateFrom, Result, ShaderType, StorageBuffer, WriteInto};
+
+/// Writes `value` into a new `Vec<u8>` (allocated with the size of `value`)
+///
+/// Shorthand for writing `value` with a [`StorageBuffer`] wrapping an empty `Vec<u8>`
+///
+/// # Examples
+///
+/// ```
+/// let bytes = encase::to_vec(&mint::Vector3 { x: 1u32, y: 2, z: 3 }).unwrap();
+///
+/// assert_eq!(bytes, [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0]);
+/// ```
+pub fn to_vec<T>(value: &T) -> Result<Vec<u8>>
+where
+    T: ?Sized + ShaderType + WriteInto,
+{
+    let mut buffer = StorageBuffer::new(Vec::with_capacity(value.size().get() as usize));
+    buffer.write(value)?;
+    Ok(buffer.into_inner())
+}
+
+/// Creates a `T` from the start of `bytes`
+///
+/// Counterpart of [`to_vec`], shorthand for creating `T` with a [`StorageBuffer`] wrapping `bytes`
+///
+/// # Examples
+///
+/// ```
+/// let bytes = encase::to_vec(&[1.0f32, 2.0]).unwrap();
+/// let value: [f32; 2] = encase::from_bytes(&bytes).unwrap();
+///
+/// assert_eq!(value, [1.0, 2.0]);
+/// ```
+pub fn from_bytes<T>(bytes: &[u8]) -> Result<T>
+where
+    T: ShaderType + CreateFrom,
+{
+    StorageBuffer::new(bytes).create()
+}
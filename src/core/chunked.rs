@@ -0,0 +1,107 @@
+use super::{BufferMut, Result, ShaderType, WriteInto, Writer};
+
+/// Writes `value` in chunks of `chunk_size` bytes, handing each one to `f`
+///
+/// All chunks are `chunk_size` bytes long except for the last one which contains the remaining bytes.
+/// Concatenating all chunks results in the same bytes a [`StorageBuffer`](crate::StorageBuffer) would contain after writing `value`
+///
+/// Useful for uploading data that is larger than what can (or should) be uploaded at once
+///
+/// # Panics
+///
+/// - if `chunk_size` is 0
+///
+/// # Examples
+///
+/// ```
+/// let data = [1u32, 2, 3, 4, 5];
+/// let mut chunks = Vec::new();
+/// encase::write_chunked(&data, 8, |chunk| chunks.push(chunk.to_vec())).unwrap();
+///
+/// assert_eq!(chunks.len(), 3);
+/// assert_eq!(chunks[2], [5, 0, 0, 0]);
+/// ```
+pub fn write_chunked<T, F>(value: &T, chunk_size: usize, f: F) -> Result<()>
+where
+    T: ?Sized + ShaderType + WriteInto,
+    F: FnMut(&[u8]),
+{
+    assert!(chunk_size != 0, "chunk size must be greater than 0");
+
+    // the chunk never needs to be bigger than the value
+    let chunk_len = chunk_size.min(value.size().get() as usize);
+    let mut buffer = ChunkedBuffer {
+        chunk: vec![0; chunk_len],
+        chunk_start: 0,
+        f,
+    };
+
+    let mut writer = Writer::new(value, &mut buffer, 0)?;
+    value.write_into(&mut writer);
+    writer.finish()?;
+
+    // flush what's left (including trailing padding that was skipped over)
+    let size = value.size().get() as usize;
+    while buffer.chunk_start < size {
+        let len = chunk_size.min(size - buffer.chunk_start);
+        buffer.flush(len);
+    }
+
+    Ok(())
+}
+
+/// Buffer that only keeps one chunk in memory
+///
+/// Relies on writes happening at increasing offsets (which is the case for all [`WriteInto`] impls),
+/// bytes that are skipped over (padding) are zeroed
+struct ChunkedBuffer<F> {
+    chunk: Vec<u8>,
+    /// offset of the current chunk
+    chunk_start: usize,
+    f: F,
+}
+
+impl<F: FnMut(&[u8])> ChunkedBuffer<F> {
+    fn flush(&mut self, len: usize) {
+        (self.f)(&self.chunk[..len]);
+        self.chunk.fill(0);
+        self.chunk_start += self.chunk.len();
+    }
+}
+
+impl<F: FnMut(&[u8])> BufferMut for ChunkedBuffer<F> {
+    fn capacity(&self) -> usize {
+        usize::MAX
+    }
+
+    #[inline]
+    fn write<const N: usize>(&mut self, offset: usize, val: &[u8; N]) {
+        self.write_slice(offset, val)
+    }
+
+    fn write_slice(&mut self, mut offset: usize, mut val: &[u8]) {
+        debug_assert!(
+            offset >= self.chunk_start,
+            "chunked writes must happen at increasing offsets"
+        );
+
+        let chunk_size = self.chunk.len();
+
+        while offset >= self.chunk_start + chunk_size {
+            self.flush(chunk_size);
+        }
+
+        while !val.is_empty() {
+            let start = offset - self.chunk_start;
+            let len = val.len().min(chunk_size - start);
+
+            self.chunk[start..start + len].copy_from_slice(&val[..len]);
+            val = &val[len..];
+            offset += len;
+
+            if start + len == chunk_size {
+                self.flush(chunk_size);
+            }
+        }
+    }
+}
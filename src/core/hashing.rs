@@ -0,0 +1,53 @@
+use super::{BufferMut, EnlargeError};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Buffer that forwards writes to `inner` while computing
+/// the 64-bit FNV-1a hash of the written bytes (in the order they are written)
+///
+/// Skipped over bytes (padding) are not part of the hash
+pub(crate) struct HashingBuffer<'a, B: ?Sized> {
+    pub(crate) inner: &'a mut B,
+    pub(crate) hash: u64,
+}
+
+impl<'a, B: ?Sized> HashingBuffer<'a, B> {
+    pub(crate) fn new(inner: &'a mut B) -> Self {
+        Self {
+            inner,
+            hash: FNV_OFFSET_BASIS,
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.hash ^= *byte as u64;
+            self.hash = self.hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+impl<B: ?Sized + BufferMut> BufferMut for HashingBuffer<'_, B> {
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    #[inline]
+    fn write<const N: usize>(&mut self, offset: usize, val: &[u8; N]) {
+        self.update(val);
+        self.inner.write(offset, val);
+    }
+
+    #[inline]
+    fn write_slice(&mut self, offset: usize, val: &[u8]) {
+        self.update(val);
+        self.inner.write_slice(offset, val);
+    }
+
+    #[inline]
+    fn try_enlarge(&mut self, wanted: usize) -> Result<(), EnlargeError> {
+        self.inner.try_enlarge(wanted)
+    }
+}
@@ -1,11 +1,24 @@
 mod alignment_value;
+mod array_reader;
 mod buffers;
+mod bytes;
+mod chunked;
+mod hashing;
 mod rw;
 mod size_value;
+mod soa;
 mod traits;
+mod type_layout;
+mod verify;
 
 pub use alignment_value::*;
+pub use array_reader::*;
 pub use buffers::*;
+pub use bytes::*;
+pub use chunked::*;
 pub use rw::*;
 pub use size_value::*;
+pub use soa::*;
 pub use traits::*;
+pub use type_layout::*;
+pub use verify::*;
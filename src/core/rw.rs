@@ -1,11 +1,81 @@
-use super::ShaderType;
+use super::{AlignmentValue, ShaderType};
 use core::mem::MaybeUninit;
 use thiserror::Error;
 
 #[derive(Clone, Copy, Debug, Error)]
+#[allow(variant_size_differences)]
 pub enum Error {
     #[error("could not read/write {expected} bytes from/into {found} byte sized buffer")]
     BufferTooSmall { expected: u64, found: u64 },
+    #[error("type can't be used in uniform buffers: {0}")]
+    UniformIncompatible(#[from] UniformCompatError),
+    #[error("offset of {offset} bytes is not aligned to alignment of {alignment} bytes")]
+    UnalignedOffset { offset: u64, alignment: u64 },
+    #[error("offset of {offset} bytes can't be recorded, dynamic offsets have to fit in a u32")]
+    OffsetTooLarge { offset: u64 },
+}
+
+/// Reason why a type doesn't meet the requirements of the uniform address space
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum UniformCompatError {
+    #[error("runtime-sized array `{ty}`{} can't be used in uniform buffers", in_field(.field))]
+    RuntimeSizedArray {
+        ty: &'static str,
+        field: Option<&'static str>,
+    },
+    #[error("stride of array `{ty}`{} must be a multiple of {min_alignment} (current stride: {stride})", in_field(.field))]
+    ArrayStride {
+        ty: &'static str,
+        field: Option<&'static str>,
+        min_alignment: u64,
+        stride: u64,
+    },
+    #[error("offset of field '{field}' must be a multiple of {min_alignment} (current offset: {offset})")]
+    FieldOffset {
+        field: &'static str,
+        min_alignment: u64,
+        offset: u64,
+    },
+    #[error("offset between fields '{prev_field}' and '{field}' must be at least {min_alignment} (currently: {diff})")]
+    FieldOffsetDiff {
+        prev_field: &'static str,
+        field: &'static str,
+        min_alignment: u64,
+        diff: u64,
+    },
+}
+
+impl UniformCompatError {
+    /// Sets the field the array is in (if not already set by a struct nested in that field)
+    ///
+    /// Used by the derive macro so that errors coming from nested types name the field that contains the culprit
+    pub fn in_field(self, name: &'static str) -> Self {
+        match self {
+            Self::RuntimeSizedArray { ty, field: None } => Self::RuntimeSizedArray {
+                ty,
+                field: Some(name),
+            },
+            Self::ArrayStride {
+                ty,
+                field: None,
+                min_alignment,
+                stride,
+            } => Self::ArrayStride {
+                ty,
+                field: Some(name),
+                min_alignment,
+                stride,
+            },
+            err => err,
+        }
+    }
+}
+
+fn in_field(field: &Option<&'static str>) -> String {
+    match field {
+        Some(field) => format!(" in field '{field}'"),
+        None => String::new(),
+    }
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
@@ -17,60 +87,223 @@ pub struct WriteContext {
     pub rts_array_length: Option<u32>,
 }
 
+/// Writes bytes into a [`BufferMut`] at increasing offsets
+///
+/// This is the supported surface for implementing [`WriteInto`](super::WriteInto) by hand:
+/// an impl writes the bytes of its value with [`Self::write`] (or [`Self::write_slice`])
+/// and skips over padding with [`Self::advance`], the capacity of the buffer has already been checked
+/// (for the size returned by [`ShaderType::size`]) when the writer was created
+///
+/// # Examples
+///
+/// ```
+/// # use encase::{internal::{BufferMut, Metadata, WriteInto, Writer}, ShaderSize, ShaderType, StorageBuffer};
+/// /// A `vec2<u32>` in the shader
+/// struct Range {
+///     start: u32,
+///     end: u32,
+/// }
+///
+/// impl ShaderType for Range {
+///     type ExtraMetadata = ();
+///     const METADATA: Metadata<()> = Metadata::from_alignment_and_size(8, 8);
+/// }
+///
+/// impl ShaderSize for Range {}
+///
+/// impl WriteInto for Range {
+///     fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+///         writer.write(&self.start.to_le_bytes());
+///         writer.write(&self.end.to_le_bytes());
+///     }
+/// }
+///
+/// let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+/// buffer.write(&Range { start: 1, end: 5 }).unwrap();
+/// assert_eq!(buffer.as_ref(), &[1, 0, 0, 0, 5, 0, 0, 0]);
+/// ```
 pub struct Writer<B: BufferMut> {
     pub ctx: WriteContext,
     cursor: Cursor<B>,
+    zero_padding: bool,
+    canonical_floats: bool,
+    /// offset the writer started at
+    start: usize,
+    /// offset past which no bytes will be written
+    end: usize,
+    /// offset past the furthest write that didn't fit
+    overflow_end: Option<usize>,
 }
 
 impl<B: BufferMut> Writer<B> {
+    /// Creates a writer that starts at `offset` and has room for `data`
+    ///
+    /// Returns [`Error::BufferTooSmall`] if the buffer can't hold (or be enlarged to hold) `data`
     #[inline]
     pub fn new<T: ?Sized + ShaderType>(data: &T, buffer: B, offset: usize) -> Result<Self> {
+        Self::with_size(data.size().get(), buffer, offset)
+    }
+
+    /// Like [`Self::new`] but for data of a known `size` (in bytes)
+    ///
+    /// Useful when there is no single value to get the size from (e.g. when writing the items of an iterator)
+    ///
+    /// Writes past `size` bytes are dropped instead of panicking,
+    /// call [`Self::finish`] once done to find out if any were
+    #[inline]
+    pub fn with_size(size: u64, buffer: B, offset: usize) -> Result<Self> {
         let mut cursor = Cursor::new(buffer, offset);
-        let size = data.size().get();
-        if cursor.try_enlarge(offset + size as usize).is_err() {
-            Err(Error::BufferTooSmall {
-                expected: size,
-                found: cursor.capacity() as u64,
-            })
-        } else {
-            Ok(Self {
+        let end = usize::try_from(size)
+            .ok()
+            .and_then(|size| offset.checked_add(size));
+        match end {
+            Some(end) if cursor.try_enlarge(end).is_ok() => Ok(Self {
                 ctx: WriteContext {
                     rts_array_length: None,
                 },
                 cursor,
-            })
+                zero_padding: false,
+                canonical_floats: false,
+                start: offset,
+                end,
+                overflow_end: None,
+            }),
+            _ => Err(Error::BufferTooSmall {
+                expected: size,
+                found: cursor.capacity() as u64,
+            }),
         }
     }
 
+    /// Makes [`Self::advance`] write zeros instead of skipping over the bytes
+    ///
+    /// Useful when the buffer is reused and its contents should not depend on what was previously written
+    #[inline]
+    pub fn set_zero_padding(&mut self, zero_padding: bool) {
+        self.zero_padding = zero_padding;
+    }
+
+    /// Makes `f32`s be written in a canonical form: NaNs as the quiet NaN `0x7FC00000` and `-0.0` as `0.0`
+    ///
+    /// Used by [`Canonical`](crate::Canonical)
+    #[inline]
+    pub fn set_canonical_floats(&mut self, canonical_floats: bool) {
+        self.canonical_floats = canonical_floats;
+    }
+
+    /// Returns whether `f32`s are written in a canonical form (see [`Self::set_canonical_floats`])
+    #[inline]
+    pub fn canonical_floats(&self) -> bool {
+        self.canonical_floats
+    }
+
+    /// Skips over `amount` bytes (or zeroes them, see [`Self::set_zero_padding`])
     #[inline]
     pub fn advance(&mut self, amount: usize) {
-        self.cursor.advance(amount);
+        if self.zero_padding {
+            self.write_zeros(amount);
+        } else {
+            self.cursor.advance(amount);
+        }
     }
 
+    /// Writes `val` at the current position and advances past it
     #[inline]
     pub fn write<const N: usize>(&mut self, val: &[u8; N]) {
-        self.cursor.write(val);
+        if self.fits(N) {
+            self.cursor.write(val);
+        }
     }
 
+    /// Like [`Self::write`] but for slices
     #[inline]
     pub fn write_slice(&mut self, val: &[u8]) {
-        self.cursor.write_slice(val)
+        if self.fits(val.len()) {
+            self.cursor.write_slice(val)
+        }
+    }
+
+    /// Checks that `amount` bytes can be written at the current position
+    ///
+    /// If they can't, the position is moved past them and the overflow is recorded
+    /// (to be reported by [`Self::finish`]) instead of writing out of bounds
+    #[inline]
+    fn fits(&mut self, amount: usize) -> bool {
+        match self.cursor.pos.checked_add(amount) {
+            Some(end) if end <= self.end => true,
+            end => {
+                let end = end.unwrap_or(usize::MAX);
+                self.overflow_end = Some(self.overflow_end.map_or(end, |prev| prev.max(end)));
+                self.cursor.pos = end;
+                false
+            }
+        }
+    }
+
+    /// Returns [`Error::BufferTooSmall`] if any of the writes didn't fit in the space
+    /// the writer was created for (e.g. the size of the data was miscomputed)
+    #[inline]
+    pub fn finish(self) -> Result<()> {
+        match self.overflow_end {
+            Some(overflow_end) => Err(Error::BufferTooSmall {
+                expected: (overflow_end - self.start) as u64,
+                found: (self.end - self.start) as u64,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Writes `amount` zero bytes
+    #[inline]
+    pub fn write_zeros(&mut self, amount: usize) {
+        const ZEROS: [u8; 64] = [0; 64];
+        let mut remaining = amount;
+        while remaining > 0 {
+            let len = remaining.min(ZEROS.len());
+            self.write_slice(&ZEROS[..len]);
+            remaining -= len;
+        }
+    }
+
+    /// Returns the offset (in bytes) at which the next write will happen
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.cursor.pos
+    }
+
+    /// Writes zeros until the position is a multiple of `align`
+    ///
+    /// # Panics
+    ///
+    /// - if `align` is not a power of two
+    #[inline]
+    pub fn pad_to_alignment(&mut self, align: u64) {
+        let padding = AlignmentValue::new(align).padding_needed_for(self.cursor.pos as u64);
+        self.write_zeros(padding as usize);
     }
 }
 
 pub struct ReadContext {
     /// max elements to read into the contained runtime sized array
     ///
-    /// used by the derive macro
+    /// set when reading an [`ArrayLength`](crate::ArrayLength) and reset to `None` by the runtime sized array that uses it
+    /// (so that it doesn't apply to any array read afterwards)
     pub rts_array_max_el_to_read: Option<u32>,
 }
 
+/// Reads bytes from a [`BufferRef`] at increasing offsets
+///
+/// The counterpart of [`Writer`] for implementing [`ReadFrom`](super::ReadFrom) and [`CreateFrom`](super::CreateFrom) by hand:
+/// an impl reads the bytes of its value with [`Self::read`] (or [`Self::read_slice`]) and skips over padding with [`Self::advance`]
 pub struct Reader<B: BufferRef> {
     pub ctx: ReadContext,
     cursor: Cursor<B>,
 }
 
 impl<B: BufferRef> Reader<B> {
+    /// Creates a reader that starts at `offset`
+    ///
+    /// Returns [`Error::BufferTooSmall`] if the buffer can't hold the minimum size of `T`
     #[inline]
     pub fn new<T: ?Sized + ShaderType>(buffer: B, offset: usize) -> Result<Self> {
         let cursor = Cursor::new(buffer, offset);
@@ -89,25 +322,46 @@ impl<B: BufferRef> Reader<B> {
         }
     }
 
+    /// Skips over `amount` bytes
     #[inline]
     pub fn advance(&mut self, amount: usize) {
         self.cursor.advance(amount);
     }
 
+    /// Reads `N` bytes at the current position and advances past them
     #[inline]
     pub fn read<const N: usize>(&mut self) -> &[u8; N] {
         self.cursor.read()
     }
 
+    /// Like [`Self::read`] but fills `val`
     #[inline]
     pub fn read_slice(&mut self, val: &mut [u8]) {
         self.cursor.read_slice(val)
     }
 
+    /// Returns the number of bytes left in the buffer (after the current position)
     #[inline]
     pub fn remaining(&self) -> usize {
         self.cursor.remaining()
     }
+
+    /// Returns the offset (in bytes) at which the next read will happen
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.cursor.pos
+    }
+
+    /// Advances the position to the next multiple of `align`
+    ///
+    /// # Panics
+    ///
+    /// - if `align` is not a power of two
+    #[inline]
+    pub fn skip_to_alignment(&mut self, align: u64) {
+        let padding = AlignmentValue::new(align).padding_needed_for(self.cursor.pos as u64);
+        self.cursor.advance(padding as usize);
+    }
 }
 
 struct Cursor<B> {
@@ -207,6 +461,10 @@ pub trait BufferMut {
             Ok(())
         }
     }
+
+    /// Shortens the buffer to `len` bytes (does nothing for buffers that can't shrink)
+    #[inline]
+    fn truncate(&mut self, _len: usize) {}
 }
 
 impl BufferRef for [u8] {
@@ -355,6 +613,11 @@ impl BufferMut for Vec<u8> {
         use crate::utils::ByteVecExt;
         self.try_extend(wanted).map_err(EnlargeError::from)
     }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
 }
 
 impl BufferMut for Vec<MaybeUninit<u8>> {
@@ -378,6 +641,11 @@ impl BufferMut for Vec<MaybeUninit<u8>> {
         use crate::utils::ByteVecExt;
         self.try_extend(wanted).map_err(EnlargeError::from)
     }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
 }
 
 macro_rules! impl_buffer_ref_for_wrappers {
@@ -425,6 +693,11 @@ macro_rules! impl_buffer_mut_for_wrappers {
             fn try_enlarge(&mut self, wanted: usize) -> core::result::Result<(), EnlargeError> {
                 T::try_enlarge(self, wanted)
             }
+
+            #[inline]
+            fn truncate(&mut self, len: usize) {
+                T::truncate(self, len)
+            }
         }
     )*};
 }
@@ -490,6 +763,80 @@ mod buffer_mut {
     }
 }
 
+#[cfg(test)]
+mod reader_writer {
+    use super::{Error, Reader, Writer};
+
+    #[test]
+    fn writer_alignment() {
+        let mut vec = vec![0xFF_u8; 16];
+        let mut writer = Writer::new(&[0u32; 4], &mut vec, 0).unwrap();
+
+        writer.write(&[1, 2, 3]);
+        assert_eq!(writer.position(), 3);
+
+        writer.pad_to_alignment(8);
+        assert_eq!(writer.position(), 8);
+
+        writer.pad_to_alignment(8);
+        assert_eq!(writer.position(), 8);
+
+        writer.write(&[4]);
+        assert_eq!(vec[..9], [1, 2, 3, 0, 0, 0, 0, 0, 4]);
+    }
+
+    #[test]
+    fn writer_out_of_bounds() {
+        let mut arr = [0xFF_u8; 8];
+        let mut writer = Writer::with_size(6, &mut arr, 2).unwrap();
+
+        writer.write(&[1, 2, 3, 4]);
+        writer.write_slice(&[5, 6, 7]);
+        writer.write(&[8]);
+
+        assert!(matches!(
+            writer.finish(),
+            Err(Error::BufferTooSmall {
+                expected: 8,
+                found: 6
+            })
+        ));
+        assert_eq!(arr, [0xFF, 0xFF, 1, 2, 3, 4, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn writer_size_overflow() {
+        let mut arr = [0_u8; 8];
+
+        assert!(matches!(
+            Writer::with_size(u64::MAX, &mut arr, 1),
+            Err(Error::BufferTooSmall {
+                expected: u64::MAX,
+                found: 7
+            })
+        ));
+    }
+
+    #[test]
+    fn reader_alignment() {
+        let vec: Vec<u8> = (0..16).collect();
+        let mut reader = Reader::new::<[u32; 4]>(&vec, 0).unwrap();
+
+        assert_eq!(reader.read::<2>(), &[0, 1]);
+        assert_eq!(reader.position(), 2);
+
+        reader.skip_to_alignment(4);
+        assert_eq!(reader.position(), 4);
+
+        reader.skip_to_alignment(4);
+        assert_eq!(reader.read::<1>(), &[4]);
+
+        reader.skip_to_alignment(16);
+        assert_eq!(reader.position(), 16);
+        assert_eq!(reader.remaining(), 0);
+    }
+}
+
 #[cfg(test)]
 mod error {
     use super::Error;
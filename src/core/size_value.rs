@@ -16,6 +16,15 @@ impl SizeValue {
         }
     }
 
+    /// Like [`Self::new`] but returns `None` (instead of panicking) if `val` is 0
+    #[inline]
+    pub const fn try_new(val: u64) -> Option<Self> {
+        match NonZeroU64::new(val) {
+            Some(val) => Some(Self(val)),
+            None => None,
+        }
+    }
+
     #[inline]
     pub const fn from(val: NonZeroU64) -> Self {
         Self(val)
@@ -53,6 +62,12 @@ mod test {
         SizeValue::new(0);
     }
 
+    #[test]
+    fn try_new() {
+        assert_eq!(Some(SizeValue::new(4)), SizeValue::try_new(4));
+        assert_eq!(None, SizeValue::try_new(0));
+    }
+
     #[test]
     fn mul() {
         assert_eq!(SizeValue::new(64), SizeValue::new(8).mul(8));
@@ -0,0 +1,55 @@
+use super::{BufferMut, Result, ShaderSize, ShaderType, StorageBuffer, WriteInto};
+
+/// Writes the pairs of items of `a` and `b` (structure of arrays) as a runtime-sized array
+/// of the elements `f` maps them to (array of structures)
+///
+/// The result is the same as writing a `Vec<Out>` containing the mapped pairs
+/// but without having to allocate it
+///
+/// # Panics
+///
+/// - if `a` and `b` have different lengths
+///
+/// # Examples
+///
+/// ```
+/// # use encase::{ShaderType, StorageBuffer};
+/// #[derive(ShaderType)]
+/// struct Particle {
+///     position: mint::Vector3<f32>,
+///     color: mint::Vector4<f32>,
+/// }
+///
+/// let positions = [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]];
+/// let colors = [[1.0, 0.0, 0.0, 1.0], [0.0, 1.0, 0.0, 1.0]];
+///
+/// let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+/// encase::soa::write2(&positions, &colors, &mut buffer, |position, color| Particle {
+///     position: (*position).into(),
+///     color: (*color).into(),
+/// })
+/// .unwrap();
+///
+/// assert_eq!(buffer.as_ref().len(), 64);
+/// ```
+pub fn write2<A, B, Out, Buf, F>(
+    a: &[A],
+    b: &[B],
+    buffer: &mut StorageBuffer<Buf>,
+    mut f: F,
+) -> Result<()>
+where
+    Out: ShaderType + ShaderSize + WriteInto,
+    Buf: BufferMut,
+    F: FnMut(&A, &B) -> Out,
+{
+    assert_eq!(
+        a.len(),
+        b.len(),
+        "the slices must have the same length ({} != {})",
+        a.len(),
+        b.len()
+    );
+
+    buffer.write_iter(a.iter().zip(b).map(|(a, b)| f(a, b)))
+}
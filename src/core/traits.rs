@@ -1,6 +1,9 @@
 use std::num::NonZeroU64;
 
-use super::{AlignmentValue, BufferMut, BufferRef, Reader, SizeValue, Writer};
+use super::{
+    AlignmentValue, BufferMut, BufferRef, Reader, SizeValue, TypeLayout, TypeLayoutKind,
+    UniformCompatError, Writer,
+};
 
 const UNIFORM_MIN_ALIGNMENT: AlignmentValue = AlignmentValue::new(16);
 
@@ -92,9 +95,21 @@ pub trait ShaderType {
     /// this will be calculated by assuming the array has one element
     #[inline]
     fn min_size() -> NonZeroU64 {
-        Self::METADATA.min_size().0
+        Self::MIN_SIZE
     }
 
+    /// Const version of [`Self::min_size`], usable in const contexts (e.g. for sizing arrays)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crate::encase::ShaderType;
+    /// const SIZE: u64 = <[mint::Vector3<f32>; 2]>::MIN_SIZE.get();
+    /// let staging = [0u8; SIZE as usize];
+    /// assert_eq!(staging.len(), 32);
+    /// ```
+    const MIN_SIZE: NonZeroU64 = Self::METADATA.min_size().0;
+
     /// Returns the size of `Self` at runtime
     ///
     /// For [WGSL fixed-footprint types](https://gpuweb.github.io/gpuweb/wgsl/#fixed-footprint-types)
@@ -104,8 +119,77 @@ pub trait ShaderType {
         Self::METADATA.min_size().0
     }
 
+    /// Whether the in-memory representation of `Self` matches its shader representation
+    ///
+    /// For structs using the [`derive@ShaderType`] derive macro this is only the case
+    /// if they are `#[repr(C)]`, all fields are pod and there is no padding
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crate::encase::ShaderType;
+    /// assert!(<[u32; 4]>::IS_POD);
+    /// assert!(!<[mint::Vector3<f32>; 4]>::IS_POD);
+    /// ```
+    const IS_POD: bool = Self::METADATA.is_pod();
+
+    /// Returns a runtime description of the layout of `Self`
+    ///
+    /// Scalars, vectors, matrices, arrays and structs using the [`derive@ShaderType`] derive macro
+    /// describe themselves, other types are [`TypeLayoutKind::Opaque`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crate::encase::ShaderType;
+    /// # use encase::{TypeLayout, TypeLayoutKind};
+    /// let layout = <[mint::Vector3<f32>; 2]>::type_layout();
+    /// assert_eq!(layout.size, 32);
+    ///
+    /// let TypeLayoutKind::Array { element, stride, len } = layout.kind else { unreachable!() };
+    /// assert_eq!((stride, len), (16, Some(2)));
+    /// assert_eq!(
+    ///     *element,
+    ///     TypeLayout { alignment: 16, size: 12, kind: TypeLayoutKind::Vector { len: 3 } }
+    /// );
+    /// ```
+    fn type_layout() -> TypeLayout {
+        TypeLayout {
+            alignment: Self::METADATA.alignment().get(),
+            size: Self::METADATA.min_size().get(),
+            kind: TypeLayoutKind::Opaque,
+        }
+    }
+
     #[doc(hidden)]
-    const UNIFORM_COMPAT_ASSERT: fn() = || {};
+    const UNIFORM_COMPAT_CHECK: fn() -> Result<(), UniformCompatError> = || Ok(());
+
+    /// Checks that `Self` meets the requirements of the
+    /// [uniform address space restrictions on stored values](https://gpuweb.github.io/gpuweb/wgsl/#address-spaces-uniform) and the
+    /// [uniform address space layout constraints](https://gpuweb.github.io/gpuweb/wgsl/#address-space-layout-constraints)
+    ///
+    /// Non-panicking version of [`Self::assert_uniform_compat`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crate::encase::ShaderType;
+    /// # use encase::internal::UniformCompatError;
+    /// assert_eq!(
+    ///     <[f32; 2]>::check_uniform_compat(),
+    ///     Err(UniformCompatError::ArrayStride {
+    ///         ty: "[f32; 2]",
+    ///         field: None,
+    ///         min_alignment: 16,
+    ///         stride: 4
+    ///     })
+    /// );
+    /// assert_eq!(<[mint::Vector4<f32>; 2]>::check_uniform_compat(), Ok(()));
+    /// ```
+    #[inline]
+    fn check_uniform_compat() -> Result<(), UniformCompatError> {
+        Self::UNIFORM_COMPAT_CHECK()
+    }
 
     /// Asserts that `Self` meets the requirements of the
     /// [uniform address space restrictions on stored values](https://gpuweb.github.io/gpuweb/wgsl/#address-spaces-uniform) and the
@@ -205,33 +289,150 @@ pub trait ShaderType {
     /// Valid::assert_uniform_compat();
     /// ```
     #[inline]
+    #[track_caller]
     fn assert_uniform_compat() {
-        Self::UNIFORM_COMPAT_ASSERT();
+        if let Err(err) = Self::check_uniform_compat() {
+            panic!("{}", err);
+        }
+    }
+
+    /// Does nothing at runtime, only compiles if `Self` implements [`WriteInto`]
+    ///
+    /// Useful for surfacing missing trait implementations early in generic code
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crate::encase::ShaderType;
+    /// <[u32; 4]>::assert_can_write_into();
+    /// ```
+    #[inline]
+    fn assert_can_write_into()
+    where
+        Self: WriteInto,
+    {
     }
 
-    // fn assert_can_write_into()
-    // where
-    //     Self: WriteInto,
-    // {
-    // }
-
-    // fn assert_can_read_from()
-    // where
-    //     Self: ReadFrom,
-    // {
-    // }
-
-    // fn assert_can_create_from()
-    // where
-    //     Self: CreateFrom,
-    // {
-    // }
+    /// Does nothing at runtime, only compiles if `Self` implements [`ReadFrom`]
+    ///
+    /// See [`Self::assert_can_write_into`]
+    #[inline]
+    fn assert_can_read_from()
+    where
+        Self: ReadFrom,
+    {
+    }
+
+    /// Does nothing at runtime, only compiles if `Self` implements [`CreateFrom`]
+    ///
+    /// See [`Self::assert_can_write_into`]
+    #[inline]
+    fn assert_can_create_from()
+    where
+        Self: CreateFrom,
+    {
+    }
+
+    /// Asserts (at compile time) that the in-memory representation of `Self` matches its shader representation
+    ///
+    /// If it does, values (and slices of values) of `Self` can be directly reinterpreted as bytes
+    /// (e.g. via `bytemuck::cast_slice`) instead of being written field by field
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crate::encase::ShaderType;
+    /// <[mint::Vector4<f32>; 4]>::assert_bytemuck_compatible();
+    /// ```
+    ///
+    /// Fails to compile since `vec3<f32>`s in arrays are padded to 16 bytes
+    ///
+    /// ```compile_fail
+    /// # use crate::encase::ShaderType;
+    /// <[mint::Vector3<f32>; 4]>::assert_bytemuck_compatible();
+    /// ```
+    #[inline]
+    fn assert_bytemuck_compatible()
+    where
+        Self: Sized,
+    {
+        trait Check {
+            const ASSERT: ();
+        }
+        impl<T: ShaderType> Check for T {
+            const ASSERT: () = {
+                let size = core::mem::size_of::<T>() as u64;
+                let min_size = T::METADATA.min_size().get();
+                const_panic::concat_assert!(
+                    T::METADATA.is_pod() && size == min_size,
+                    "the in-memory representation of the type (size: ",
+                    size,
+                    ") doesn't match its shader representation (size: ",
+                    min_size,
+                    ")"
+                );
+            };
+        }
+        #[allow(clippy::let_unit_value)]
+        let _ = <Self as Check>::ASSERT;
+    }
 }
 
 /// Trait implemented for all [WGSL fixed-footprint types](https://gpuweb.github.io/gpuweb/wgsl/#fixed-footprint-types)
 pub trait ShaderSize: ShaderType {
     /// Represents [WGSL Size](https://gpuweb.github.io/gpuweb/wgsl/#alignment-and-size) (equivalent to [`ShaderType::min_size`])
     const SHADER_SIZE: NonZeroU64 = Self::METADATA.min_size().0;
+
+    /// Returns the [stride](https://gpuweb.github.io/gpuweb/wgsl/#array-layout-rules)
+    /// of an array containing elements of type `Self`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crate::encase::ShaderSize;
+    /// assert_eq!(<mint::Vector3<f32>>::array_stride(), 16);
+    /// assert_eq!(<mint::Vector2<f32>>::array_stride(), 8);
+    /// ```
+    #[inline]
+    fn array_stride() -> u64 {
+        Self::METADATA.alignment().round_up(Self::SHADER_SIZE.get())
+    }
+}
+
+/// Asserts at compile time that the [`ShaderSize::SHADER_SIZE`] of the given type equals the given size
+///
+/// Useful to make sure a type keeps matching a layout that is hardcoded elsewhere (e.g. in a shader)
+///
+/// # Examples
+///
+/// ```
+/// # use encase::{assert_shader_size, ShaderType};
+/// #[derive(ShaderType)]
+/// struct Light {
+///     position: mint::Vector3<f32>,
+///     intensity: f32,
+/// }
+///
+/// assert_shader_size!(Light, 16);
+/// ```
+#[macro_export]
+macro_rules! assert_shader_size {
+    ($type:ty, $size:expr $(,)?) => {
+        const _: () = {
+            let expected: ::core::primitive::u64 = $size;
+            let size = <$type as $crate::private::ShaderSize>::SHADER_SIZE.get();
+            $crate::private::concat_assert!(
+                size == expected,
+                "expected shader size of `",
+                ::core::stringify!($type),
+                "` to be ",
+                expected,
+                " (actual size: ",
+                size,
+                ")"
+            );
+        };
+    };
 }
 
 /// Trait implemented for
@@ -241,6 +442,31 @@ pub trait ShaderSize: ShaderType {
 pub trait CalculateSizeFor {
     /// Returns the size of `Self` assuming the (contained) runtime-sized array has `nr_of_el` elements
     fn calculate_size_for(nr_of_el: u64) -> NonZeroU64;
+
+    /// Returns the size of the part of `Self` that comes before the (contained) runtime-sized array
+    /// (0 for runtime-sized arrays)
+    ///
+    /// Unlike [`Self::calculate_size_for`] (and [`ShaderType::min_size`]), which account for at least one element,
+    /// this doesn't include any element of the array
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use encase::{CalculateSizeFor, ShaderType};
+    /// #[derive(ShaderType)]
+    /// struct Positions {
+    ///     count: u32,
+    ///     #[size(runtime)]
+    ///     positions: Vec<mint::Vector4<f32>>,
+    /// }
+    ///
+    /// assert_eq!(Positions::header_size(), 16);
+    /// assert_eq!(Positions::min_size().get(), 32);
+    /// ```
+    #[inline]
+    fn header_size() -> u64 {
+        0
+    }
 }
 
 #[allow(clippy::len_without_is_empty)]
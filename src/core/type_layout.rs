@@ -0,0 +1,48 @@
+/// Runtime description of the layout of a [`ShaderType`](super::ShaderType)
+///
+/// Returned by [`ShaderType::type_layout`](super::ShaderType::type_layout),
+/// useful for validating host types against a reflected shader interface
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeLayout {
+    /// Alignment of the type (in bytes)
+    pub alignment: u64,
+    /// Size of the type (in bytes), see [`ShaderType::min_size`](super::ShaderType::min_size)
+    pub size: u64,
+    pub kind: TypeLayoutKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypeLayoutKind {
+    Scalar,
+    Vector {
+        len: u64,
+    },
+    Matrix {
+        columns: u64,
+        rows: u64,
+    },
+    /// `len` is `None` for runtime-sized arrays
+    Array {
+        element: Box<TypeLayout>,
+        stride: u64,
+        len: Option<u64>,
+    },
+    Struct {
+        fields: Vec<FieldLayout>,
+    },
+    /// Types that are not described any further (e.g. types implementing
+    /// [`ShaderType`](super::ShaderType) by hand that don't override
+    /// [`ShaderType::type_layout`](super::ShaderType::type_layout))
+    Opaque,
+}
+
+/// Layout of a struct field
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldLayout {
+    pub name: &'static str,
+    /// Offset of the field from the start of the struct (in bytes)
+    pub offset: u64,
+    /// Padding after the field (in bytes)
+    pub padding: u64,
+    pub layout: TypeLayout,
+}
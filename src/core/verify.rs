@@ -0,0 +1,68 @@
+use super::{ShaderSize, ShaderType, TypeLayoutKind};
+use thiserror::Error;
+
+/// Reason why the in-memory representation of a type doesn't match its shader representation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum LayoutMismatch {
+    #[error("size of the type in memory ({rust}) doesn't match its shader size ({shader})")]
+    Size { rust: u64, shader: u64 },
+    #[error("stride of arrays of the type in memory ({rust}) doesn't match their shader stride ({shader})")]
+    ArrayStride { rust: u64, shader: u64 },
+    #[error("shader representation has {padding} bytes of padding after field '{field}'")]
+    FieldPadding { field: &'static str, padding: u64 },
+    #[error("in-memory representation of the type doesn't match its shader representation")]
+    NotPod,
+}
+
+/// Checks whether values (and slices of values) of `T` can be directly reinterpreted as bytes
+/// (e.g. via `bytemuck::cast_slice`) and bound as is instead of being written with encase
+///
+/// Runtime version of [`ShaderType::assert_bytemuck_compatible`] that returns why they can't,
+/// the size and the stride of arrays are compared against [`size_of`](core::mem::size_of)
+/// and structs using the [`derive@ShaderType`](crate::ShaderType) derive macro report the first field followed by padding
+///
+/// # Examples
+///
+/// ```
+/// # use encase::{verify_layout, LayoutMismatch};
+/// assert_eq!(verify_layout::<[mint::Vector4<f32>; 4]>(), Ok(()));
+///
+/// // `vec3<f32>`s in arrays are padded to 16 bytes
+/// assert_eq!(
+///     verify_layout::<[mint::Vector3<f32>; 4]>(),
+///     Err(LayoutMismatch::Size { rust: 48, shader: 64 })
+/// );
+/// ```
+pub fn verify_layout<T: ShaderSize>() -> Result<(), LayoutMismatch> {
+    let rust_size = core::mem::size_of::<T>() as u64;
+    let shader_size = T::SHADER_SIZE.get();
+    if rust_size != shader_size {
+        return Err(LayoutMismatch::Size {
+            rust: rust_size,
+            shader: shader_size,
+        });
+    }
+
+    let shader_stride = T::METADATA.alignment().round_up(shader_size);
+    if rust_size != shader_stride {
+        return Err(LayoutMismatch::ArrayStride {
+            rust: rust_size,
+            shader: shader_stride,
+        });
+    }
+
+    if T::METADATA.is_pod() {
+        return Ok(());
+    }
+
+    if let TypeLayoutKind::Struct { fields } = T::type_layout().kind {
+        if let Some(field) = fields.iter().find(|field| field.padding != 0) {
+            return Err(LayoutMismatch::FieldPadding {
+                field: field.name,
+                padding: field.padding,
+            });
+        }
+    }
+
+    Err(LayoutMismatch::NotPod)
+}
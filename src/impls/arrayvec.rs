@@ -1,4 +1,35 @@
-use crate::rts_array::impl_rts_array;
+use crate::rts_array::{impl_rts_array, Length};
 
-// hardcap
-impl_rts_array!(arrayvec::ArrayVec<T, N>; (T, const N: usize); using len truncate);
+// hardcap (reads stop after `N` elements)
+impl_rts_array!(arrayvec::ArrayVec<T, N>; (T, const N: usize); using truncate);
+
+impl<T, const N: usize> Length for arrayvec::ArrayVec<T, N> {
+    const CAPACITY: Option<usize> = Some(N);
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::StorageBuffer;
+    use arrayvec::ArrayVec;
+
+    #[test]
+    fn reads_are_capped_at_capacity() {
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&[1u32, 2, 3, 4, 5, 6][..]).unwrap();
+
+        let created: ArrayVec<u32, 4> = buffer.create().unwrap();
+        assert_eq!(created.as_slice(), &[1, 2, 3, 4]);
+
+        let mut read = ArrayVec::<u32, 4>::new();
+        read.push(7);
+        buffer.read(&mut read).unwrap();
+        assert_eq!(read.as_slice(), &[1, 2, 3, 4]);
+
+        let created: ArrayVec<u32, 8> = buffer.create().unwrap();
+        assert_eq!(created.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    }
+}
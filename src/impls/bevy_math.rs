@@ -0,0 +1,65 @@
+use crate::{
+    core::{BufferMut, Metadata, TypeLayout, WriteInto, Writer},
+    ShaderSize, ShaderType,
+};
+
+// `bevy_math` re-exports `glam`'s types (those are covered by the `glam` impls),
+// its directions are laid out like the vectors they wrap.
+// Directions are write-only, a vector written by a shader is not guaranteed to be normalized
+// (or even non-zero), read it as the wrapped vector and use `new` to handle invalid ones.
+macro_rules! impl_direction {
+    ($type:ty, $vector:ty) => {
+        impl ShaderType for $type {
+            type ExtraMetadata = ();
+            const METADATA: Metadata<Self::ExtraMetadata> = <$vector>::METADATA;
+
+            fn type_layout() -> TypeLayout {
+                <$vector>::type_layout()
+            }
+        }
+
+        impl ShaderSize for $type {}
+
+        impl WriteInto for $type {
+            #[inline]
+            fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+                WriteInto::write_into(&**self, writer);
+            }
+        }
+    };
+}
+
+impl_direction!(bevy_math::Dir2, bevy_math::Vec2);
+impl_direction!(bevy_math::Dir3, bevy_math::Vec3);
+impl_direction!(bevy_math::Dir3A, bevy_math::Vec3A);
+
+#[cfg(test)]
+mod test {
+    use crate::{ShaderType, StorageBuffer};
+    use bevy_math::{Dir2, Dir3, Dir3A, Vec2, Vec3, Vec3A};
+
+    #[test]
+    fn directions() {
+        assert_eq!(Dir2::METADATA.alignment().get(), 8);
+        assert_eq!(Dir3::min_size().get(), 12);
+        assert_eq!(Dir3A::METADATA.alignment().get(), 16);
+
+        let dir = Dir3::new(Vec3::new(1.0, 2.0, 2.0)).unwrap();
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&dir).unwrap();
+
+        let vector: Vec3 = buffer.create().unwrap();
+        assert_eq!(vector, *dir);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&[Dir2::X, Dir2::NEG_Y]).unwrap();
+        let vectors: [Vec2; 2] = buffer.create().unwrap();
+        assert_eq!(vectors, [Vec2::X, Vec2::NEG_Y]);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&Dir3A::Z).unwrap();
+        assert_eq!(buffer.as_ref().len(), 16);
+        let vector: Vec3A = buffer.create().unwrap();
+        assert_eq!(vector, Vec3A::Z);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    core::{BufferMut, BufferRef, CreateFrom, Metadata, ReadFrom, Reader, WriteInto, Writer},
+    ShaderSize, ShaderType,
+};
+use chrono::{DateTime, Utc};
+
+// `DateTime<Utc>`s are laid out like a `vec2<i32>` containing the seconds since the unix epoch
+// and the nanoseconds since the last whole second (`timestamp()` and `timestamp_subsec_nanos()`).
+// WGSL has no 64-bit integers so the seconds only cover the years 1901 to 2038,
+// timestamps outside of that range are clamped to it when written.
+// Values that don't form a valid timestamp when read (negative or too many nanoseconds) are read as the unix epoch.
+// Note that `f32`s (the shader's only float type) can't represent the seconds to the second,
+// shaders should subtract a reference time before converting to floats.
+
+impl ShaderType for DateTime<Utc> {
+    type ExtraMetadata = ();
+    const METADATA: Metadata<Self::ExtraMetadata> = Metadata::from_alignment_and_size(8, 8);
+}
+
+impl ShaderSize for DateTime<Utc> {}
+
+impl WriteInto for DateTime<Utc> {
+    #[inline]
+    fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+        let secs = self.timestamp().clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+        let nanos = self.timestamp_subsec_nanos() as i32;
+        WriteInto::write_into(&[secs, nanos], writer);
+    }
+}
+
+impl ReadFrom for DateTime<Utc> {
+    #[inline]
+    fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+        *self = CreateFrom::create_from(reader);
+    }
+}
+
+impl CreateFrom for DateTime<Utc> {
+    #[inline]
+    fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+        let [secs, nanos]: [i32; 2] = CreateFrom::create_from(reader);
+        u32::try_from(nanos)
+            .ok()
+            .and_then(|nanos| DateTime::from_timestamp(secs as i64, nanos))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ShaderType, StorageBuffer};
+    use chrono::{DateTime, TimeZone, Utc};
+
+    #[test]
+    fn round_trip() {
+        assert_eq!(DateTime::<Utc>::METADATA.alignment().get(), 8);
+        assert_eq!(DateTime::<Utc>::min_size().get(), 8);
+
+        let timestamps = [
+            Utc.with_ymd_and_hms(2024, 9, 13, 12, 30, 15).unwrap(),
+            DateTime::from_timestamp(-86_400, 123_456_789).unwrap(),
+            DateTime::from_timestamp(1_700_000_000, 999_999_999).unwrap(),
+        ];
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&timestamps).unwrap();
+
+        let raw: [[i32; 2]; 3] = buffer.create().unwrap();
+        assert_eq!(raw[0], [1_726_230_615, 0]);
+        assert_eq!(raw[1], [-86_400, 123_456_789]);
+
+        let created: [DateTime<Utc>; 3] = buffer.create().unwrap();
+        assert_eq!(created, timestamps);
+
+        let mut read = [DateTime::<Utc>::default(); 3];
+        buffer.read(&mut read).unwrap();
+        assert_eq!(read, timestamps);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let far_future = Utc.with_ymd_and_hms(2100, 1, 1, 0, 0, 0).unwrap();
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&far_future).unwrap();
+
+        let created: DateTime<Utc> = buffer.create().unwrap();
+        assert_eq!(created.timestamp(), i32::MAX as i64);
+
+        let buffer = StorageBuffer::new([0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF]);
+        let created: DateTime<Utc> = buffer.create().unwrap();
+        assert_eq!(created, DateTime::<Utc>::default());
+    }
+}
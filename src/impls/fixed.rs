@@ -0,0 +1,92 @@
+use crate::{
+    core::{BufferMut, BufferRef, CreateFrom, Metadata, ReadFrom, Reader, WriteInto, Writer},
+    ShaderSize, ShaderType,
+};
+use fixed::{types::extra::LeEqU32, FixedI32, FixedU32};
+
+// 32-bit fixed-point numbers are laid out as their raw bits (`to_bits()`), like an `i32` or `u32`.
+// WGSL has no fixed-point types, shaders have to do the fixed-point math on the integers themselves
+// (e.g. shift by the number of fractional bits).
+macro_rules! impl_fixed {
+    ($type:ident, $bits:ty) => {
+        impl<Frac: LeEqU32> ShaderType for $type<Frac> {
+            type ExtraMetadata = ();
+            const METADATA: Metadata<Self::ExtraMetadata> = <$bits>::METADATA;
+        }
+
+        impl<Frac: LeEqU32> ShaderSize for $type<Frac> {}
+
+        impl<Frac: LeEqU32> WriteInto for $type<Frac> {
+            #[inline]
+            fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+                WriteInto::write_into(&self.to_bits(), writer);
+            }
+        }
+
+        impl<Frac: LeEqU32> ReadFrom for $type<Frac> {
+            #[inline]
+            fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+                *self = CreateFrom::create_from(reader);
+            }
+        }
+
+        impl<Frac: LeEqU32> CreateFrom for $type<Frac> {
+            #[inline]
+            fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+                Self::from_bits(CreateFrom::create_from(reader))
+            }
+        }
+    };
+}
+
+impl_fixed!(FixedI32, i32);
+impl_fixed!(FixedU32, u32);
+
+#[cfg(test)]
+mod test {
+    use crate::{ShaderType, StorageBuffer};
+    use fixed::types::{I16F16, I8F24, U16F16, U24F8};
+
+    #[test]
+    fn round_trip() {
+        assert_eq!(I16F16::METADATA.alignment().get(), 4);
+        assert_eq!(U16F16::min_size().get(), 4);
+        assert!(<[I16F16; 4]>::METADATA.is_pod());
+
+        let values = [I16F16::from_num(1.5), I16F16::from_num(-2.25), I16F16::MAX];
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&values).unwrap();
+
+        let bits: [i32; 3] = buffer.create().unwrap();
+        assert_eq!(bits, values.map(I16F16::to_bits));
+        assert_eq!(bits[0], 0x0001_8000);
+
+        let created: [I16F16; 3] = buffer.create().unwrap();
+        assert_eq!(created, values);
+        assert_eq!(created[1], I16F16::from_bits(-0x0002_4000));
+    }
+
+    #[test]
+    fn other_formats() {
+        let value = (
+            U16F16::from_num(3.75),
+            I8F24::from_num(-0.5),
+            U24F8::from_bits(0xFFFF_FF80),
+        );
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&value.0).unwrap();
+        assert_eq!(buffer.create::<u32>().unwrap(), 0x0003_C000);
+        assert_eq!(buffer.create::<U16F16>().unwrap(), value.0);
+
+        buffer.write(&value.1).unwrap();
+        assert_eq!(buffer.create::<i32>().unwrap(), -0x0080_0000);
+        assert_eq!(buffer.create::<I8F24>().unwrap(), value.1);
+
+        let mut read = U24F8::ZERO;
+        buffer.write(&value.2).unwrap();
+        buffer.read(&mut read).unwrap();
+        assert_eq!(read, value.2);
+    }
+}
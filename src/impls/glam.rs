@@ -1,12 +1,26 @@
 use crate::{
+    core::{
+        BufferMut, BufferRef, CreateFrom, Metadata, ReadFrom, Reader, TypeLayout,
+        UniformCompatError, WriteInto, Writer,
+    },
     matrix::{impl_matrix, AsMutMatrixParts, AsRefMatrixParts, FromMatrixParts, MatrixScalar},
-    vector::impl_vector,
+    types::{
+        array::ArrayMetadata,
+        matrix::{MatrixMetadata, Transposed},
+        vector::{Bitcast, Packed},
+    },
+    vector::{impl_vector, FromVectorParts},
+    ShaderSize, ShaderType,
 };
 
 impl_vector!(2, glam::Vec2, f32; using AsRef AsMut From);
 impl_vector!(2, glam::UVec2, u32; using AsRef AsMut From);
 impl_vector!(2, glam::IVec2, i32; using AsRef AsMut From);
 
+// `vec3`s are 12 bytes but 16 byte aligned, so arrays of them (e.g. `[Vec3; N]` or `Vec<Vec3>`)
+// have 4 bytes of padding after each element and can't be memcpy'd (they're written element by element).
+// Use `Vec4`s (or `Vec3A`s converted to `Vec4`s) if the padding isn't wanted on the shader side either,
+// or `Packed<Vec3>`s if the shader reads the data as a flat `array<f32>`.
 impl_vector!(3, glam::Vec3, f32; using AsRef AsMut From);
 impl_vector!(3, glam::UVec3, u32; using AsRef AsMut From);
 impl_vector!(3, glam::IVec3, i32; using AsRef AsMut From);
@@ -15,6 +29,24 @@ impl_vector!(4, glam::Vec4, f32; using AsRef AsMut From);
 impl_vector!(4, glam::UVec4, u32; using AsRef AsMut From);
 impl_vector!(4, glam::IVec4, i32; using AsRef AsMut From);
 
+// 64-bit integer vectors are rejected like `u64` and `i64`
+
+impl_traits_for_unsupported_64_bit_int!(glam::U64Vec2, "`glam::UVec4`");
+impl_traits_for_unsupported_64_bit_int!(glam::U64Vec3, "`[glam::UVec2; 3]`");
+impl_traits_for_unsupported_64_bit_int!(glam::U64Vec4, "`[glam::UVec4; 2]`");
+impl_traits_for_unsupported_64_bit_int!(glam::I64Vec2, "`glam::IVec4`");
+impl_traits_for_unsupported_64_bit_int!(glam::I64Vec3, "`[glam::IVec2; 3]`");
+impl_traits_for_unsupported_64_bit_int!(glam::I64Vec4, "`[glam::IVec4; 2]`");
+
+// `Quat`s are laid out like `vec4<f32>`s (`xyzw`), no normalization happens when reading
+impl_vector!(4, glam::Quat, f32; using AsRef AsMut);
+
+impl FromVectorParts<f32, 4> for glam::Quat {
+    fn from_parts(parts: [f32; 4]) -> Self {
+        Self::from_array(parts)
+    }
+}
+
 impl_matrix!(2, 2, glam::Mat2, f32);
 impl_matrix!(3, 3, glam::Mat3, f32);
 impl_matrix!(4, 4, glam::Mat4, f32);
@@ -52,3 +84,654 @@ macro_rules! impl_matrix_traits {
 impl_matrix_traits!(2, 2, glam::Mat2, f32);
 impl_matrix_traits!(3, 3, glam::Mat3, f32);
 impl_matrix_traits!(4, 4, glam::Mat4, f32);
+
+// `Mat3A` stores its columns as `Vec3A`s (16 byte aligned) which can't be viewed as `&[[f32; 3]; 3]`,
+// so it can't go through `impl_matrix!`. The layout (and bytes written) are identical to `Mat3`'s.
+
+impl ShaderType for glam::Mat3A {
+    type ExtraMetadata = MatrixMetadata;
+    const METADATA: Metadata<Self::ExtraMetadata> = glam::Mat3::METADATA;
+}
+
+impl ShaderSize for glam::Mat3A {}
+
+impl WriteInto for glam::Mat3A {
+    #[inline]
+    fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+        for col in &self.to_cols_array_2d() {
+            WriteInto::write_into(col, writer);
+            writer.advance(Self::METADATA.col_padding() as usize);
+        }
+    }
+}
+
+impl ReadFrom for glam::Mat3A {
+    #[inline]
+    fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+        *self = CreateFrom::create_from(reader);
+    }
+}
+
+impl CreateFrom for glam::Mat3A {
+    #[inline]
+    fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+        let columns = core::array::from_fn(|_| {
+            let col = CreateFrom::create_from(reader);
+            reader.advance(Self::METADATA.col_padding() as usize);
+            col
+        });
+        FromMatrixParts::<f32, 3, 3>::from_parts(columns)
+    }
+}
+
+impl FromMatrixParts<f32, 3, 3> for glam::Mat3A {
+    fn from_parts(parts: [[f32; 3]; 3]) -> Self {
+        Self::from_cols_array_2d(&parts)
+    }
+}
+
+// `Affine3A` is laid out like the `mat4x4<f32>` it represents (e.g. `Mat4::from(affine)`).
+// When reading, the bottom row is dropped (see `Affine3A::from_mat4`),
+// so matrices that aren't affine don't round-trip.
+
+impl ShaderType for glam::Affine3A {
+    type ExtraMetadata = MatrixMetadata;
+    const METADATA: Metadata<Self::ExtraMetadata> = glam::Mat4::METADATA.no_pod();
+}
+
+impl ShaderSize for glam::Affine3A {}
+
+impl WriteInto for glam::Affine3A {
+    #[inline]
+    fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+        WriteInto::write_into(&glam::Mat4::from(*self), writer);
+    }
+}
+
+impl ReadFrom for glam::Affine3A {
+    #[inline]
+    fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+        *self = CreateFrom::create_from(reader);
+    }
+}
+
+impl CreateFrom for glam::Affine3A {
+    #[inline]
+    fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+        Self::from_mat4(CreateFrom::create_from(reader))
+    }
+}
+
+macro_rules! impl_transposed {
+    ($($type:ty),*) => {$(
+        impl ShaderType for Transposed<$type> {
+            type ExtraMetadata = MatrixMetadata;
+            const METADATA: Metadata<Self::ExtraMetadata> = <$type>::METADATA.no_pod();
+        }
+
+        impl ShaderSize for Transposed<$type> {}
+
+        impl WriteInto for Transposed<$type> {
+            #[inline]
+            fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+                WriteInto::write_into(&self.0.transpose(), writer);
+            }
+        }
+
+        impl ReadFrom for Transposed<$type> {
+            #[inline]
+            fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+                *self = CreateFrom::create_from(reader);
+            }
+        }
+
+        impl CreateFrom for Transposed<$type> {
+            #[inline]
+            fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+                Transposed(<$type as CreateFrom>::create_from(reader).transpose())
+            }
+        }
+    )*};
+}
+
+impl_transposed!(glam::Mat2, glam::Mat3, glam::Mat3A, glam::Mat4);
+
+macro_rules! impl_packed {
+    ($($type:ty, $el_ty:ty, $n:literal);* $(;)?) => {$(
+        impl ShaderType for Packed<$type> {
+            type ExtraMetadata = ArrayMetadata;
+            const METADATA: Metadata<Self::ExtraMetadata> = <[$el_ty; $n]>::METADATA.no_pod();
+
+            const UNIFORM_COMPAT_CHECK: fn() -> Result<(), UniformCompatError> =
+                <[$el_ty; $n]>::UNIFORM_COMPAT_CHECK;
+        }
+
+        impl ShaderSize for Packed<$type> {}
+
+        impl WriteInto for Packed<$type> {
+            #[inline]
+            fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+                WriteInto::write_into(&self.0.to_array(), writer);
+            }
+        }
+
+        impl ReadFrom for Packed<$type> {
+            #[inline]
+            fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+                *self = CreateFrom::create_from(reader);
+            }
+        }
+
+        impl CreateFrom for Packed<$type> {
+            #[inline]
+            fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+                Packed(<$type>::from_array(CreateFrom::create_from(reader)))
+            }
+        }
+    )*};
+}
+
+impl_packed!(
+    glam::Vec2, f32, 2;
+    glam::UVec2, u32, 2;
+    glam::IVec2, i32, 2;
+    glam::Vec3, f32, 3;
+    glam::Vec3A, f32, 3;
+    glam::UVec3, u32, 3;
+    glam::IVec3, i32, 3;
+    glam::Vec4, f32, 4;
+    glam::UVec4, u32, 4;
+    glam::IVec4, i32, 4;
+);
+
+macro_rules! impl_bitcast {
+    ($($type:ty, $uvec:ty, $n:literal);* $(;)?) => {$(
+        impl ShaderType for Bitcast<$type> {
+            type ExtraMetadata = ();
+            const METADATA: Metadata<Self::ExtraMetadata> = <$uvec>::METADATA.no_pod();
+
+            fn type_layout() -> TypeLayout {
+                <$uvec>::type_layout()
+            }
+        }
+
+        impl ShaderSize for Bitcast<$type> {}
+
+        impl WriteInto for Bitcast<$type> {
+            #[inline]
+            fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+                WriteInto::write_into(&self.0.to_array().map(f32::to_bits), writer);
+            }
+        }
+
+        impl ReadFrom for Bitcast<$type> {
+            #[inline]
+            fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+                *self = CreateFrom::create_from(reader);
+            }
+        }
+
+        impl CreateFrom for Bitcast<$type> {
+            #[inline]
+            fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+                let lanes: [u32; $n] = CreateFrom::create_from(reader);
+                Bitcast(<$type>::from_array(lanes.map(f32::from_bits)))
+            }
+        }
+    )*};
+}
+
+impl_bitcast!(
+    glam::Vec2, glam::UVec2, 2;
+    glam::Vec3, glam::UVec3, 3;
+    glam::Vec3A, glam::UVec3, 3;
+    glam::Vec4, glam::UVec4, 4;
+);
+
+// WGSL `vecN<bool>` can't be used in host-shareable types,
+// `BVecN`s (and the `BVec3A`/`BVec4A` SIMD masks) are laid out like `vecN<u32>`s instead
+// (`1` for `true` and `0` for `false`, not the all-ones lanes of the masks).
+// When reading, any non-zero lane is `true`.
+
+macro_rules! impl_bool_vector {
+    ($n:literal, $type:ty, $uvec:ty) => {
+        impl ShaderType for $type {
+            type ExtraMetadata = ();
+            const METADATA: Metadata<Self::ExtraMetadata> = <$uvec>::METADATA.no_pod();
+        }
+
+        impl ShaderSize for $type {}
+
+        impl WriteInto for $type {
+            #[inline]
+            fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+                let lanes: [u32; $n] = core::array::from_fn(|i| u32::from(self.test(i)));
+                WriteInto::write_into(&lanes, writer);
+            }
+        }
+
+        impl ReadFrom for $type {
+            #[inline]
+            fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+                *self = CreateFrom::create_from(reader);
+            }
+        }
+
+        impl CreateFrom for $type {
+            #[inline]
+            fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+                let lanes = <[u32; $n] as CreateFrom>::create_from(reader);
+                <$type>::from(lanes.map(|lane| lane != 0))
+            }
+        }
+    };
+}
+
+impl_bool_vector!(2, glam::BVec2, glam::UVec2);
+impl_bool_vector!(3, glam::BVec3, glam::UVec3);
+impl_bool_vector!(4, glam::BVec4, glam::UVec4);
+impl_bool_vector!(3, glam::BVec3A, glam::UVec3);
+impl_bool_vector!(4, glam::BVec4A, glam::UVec4);
+
+/// Returns the rows of `m`, for shaders that take a transform as four separate `vec4<f32>`s (one per row)
+///
+/// Writing the result is equivalent to writing [`Transposed(m)`](crate::Transposed)
+///
+/// # Examples
+///
+/// ```
+/// # use encase::StorageBuffer;
+/// let m = glam::Mat4::from_translation(glam::Vec3::new(1.0, 2.0, 3.0));
+/// let rows = encase::mat4_rows(&m);
+/// assert_eq!(rows[0], glam::Vec4::new(1.0, 0.0, 0.0, 1.0));
+///
+/// let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+/// buffer.write(&rows).unwrap();
+/// assert_eq!(buffer.as_ref().len(), 64);
+/// ```
+pub fn mat4_rows(m: &glam::Mat4) -> [glam::Vec4; 4] {
+    [m.row(0), m.row(1), m.row(2), m.row(3)]
+}
+
+/// Returns the columns of `m`, for shaders that take a transform as four separate `vec4<f32>`s (one per column)
+///
+/// Writing the result is equivalent to writing `m`
+pub fn mat4_cols(m: &glam::Mat4) -> [glam::Vec4; 4] {
+    [m.x_axis, m.y_axis, m.z_axis, m.w_axis]
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ShaderType, StorageBuffer};
+
+    #[test]
+    fn vec3_array_is_padded() {
+        use crate::ShaderSize;
+
+        assert_eq!(<[glam::Vec3; 4]>::SHADER_SIZE.get(), 64);
+        assert_eq!(<[glam::Vec3; 4]>::METADATA.el_padding(), 4);
+        assert!(!<[glam::Vec3; 4]>::METADATA.is_pod());
+        assert!(<[glam::Vec4; 4]>::METADATA.is_pod());
+
+        let array = [
+            glam::Vec3::new(1., 2., 3.),
+            glam::Vec3::new(4., 5., 6.),
+            glam::Vec3::new(7., 8., 9.),
+            glam::Vec3::new(10., 11., 12.),
+        ];
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&array).unwrap();
+        assert_eq!(buffer.as_ref().len(), 64);
+
+        let vec4s: [glam::Vec4; 4] = buffer.create().unwrap();
+        for (vec4, vec3) in vec4s.iter().zip(array) {
+            assert_eq!(vec4.truncate(), vec3);
+        }
+
+        let created: [glam::Vec3; 4] = buffer.create().unwrap();
+        assert_eq!(created, array);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&array.to_vec()).unwrap();
+        assert_eq!(buffer.as_ref().len(), 64);
+
+        let created: Vec<glam::Vec3> = buffer.create().unwrap();
+        assert_eq!(created, array);
+    }
+
+    #[test]
+    fn mat4_arrays_are_pod() {
+        use crate::core::BufferMut;
+
+        assert!(glam::Mat4::METADATA.is_pod());
+        assert!(<[glam::Mat4; 8]>::METADATA.is_pod());
+        assert!(!glam::Mat3::METADATA.is_pod());
+        assert_eq!(glam::Mat3::METADATA.col_padding(), 4);
+        assert!(!<[glam::Mat3; 8]>::METADATA.is_pod());
+
+        // counts the calls that reach the buffer to tell the bulk path apart from the per-element one
+        #[derive(Default)]
+        struct CountingBuffer {
+            bytes: Vec<u8>,
+            writes: usize,
+        }
+
+        impl BufferMut for CountingBuffer {
+            fn capacity(&self) -> usize {
+                self.bytes.len()
+            }
+
+            fn write<const N: usize>(&mut self, offset: usize, val: &[u8; N]) {
+                self.writes += 1;
+                self.bytes.write(offset, val);
+            }
+
+            fn write_slice(&mut self, offset: usize, val: &[u8]) {
+                self.writes += 1;
+                self.bytes.write_slice(offset, val);
+            }
+
+            fn try_enlarge(&mut self, wanted: usize) -> Result<(), crate::core::EnlargeError> {
+                self.bytes.try_enlarge(wanted)
+            }
+        }
+
+        let mats: [glam::Mat4; 4] =
+            core::array::from_fn(|i| glam::Mat4::from_cols_array(&[i as f32; 16]));
+
+        let mut buffer = StorageBuffer::new(CountingBuffer::default());
+        buffer.write(&mats).unwrap();
+        assert_eq!(buffer.as_ref().writes, 1);
+        assert_eq!(buffer.as_ref().bytes.len(), 256);
+
+        let created: [glam::Mat4; 4] = StorageBuffer::new(buffer.into_inner().bytes)
+            .create()
+            .unwrap();
+        assert_eq!(created, mats);
+
+        let mats = [glam::Mat3::IDENTITY; 4];
+
+        let mut buffer = StorageBuffer::new(CountingBuffer::default());
+        buffer.write(&mats).unwrap();
+        assert!(buffer.as_ref().writes > 1);
+        assert_eq!(buffer.as_ref().bytes.len(), 192);
+    }
+
+    #[test]
+    fn mat3_create_skips_column_padding() {
+        assert_eq!(glam::Mat3::METADATA.col_padding(), 4);
+
+        // 3 columns of 3 floats, each followed by 4 bytes of garbage padding
+        let mut bytes = Vec::new();
+        for col in [[1f32, 2., 3.], [4., 5., 6.], [7., 8., 9.]] {
+            for value in col {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+            bytes.extend_from_slice(&[0xAB; 4]);
+        }
+        let bytes = [bytes.clone(), bytes].concat();
+
+        let expected = glam::Mat3::from_cols_array(&[1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+
+        let buffer = StorageBuffer::new(bytes);
+        let created: glam::Mat3 = buffer.create().unwrap();
+        assert_eq!(created, expected);
+
+        let mut read = glam::Mat3::ZERO;
+        buffer.read(&mut read).unwrap();
+        assert_eq!(read, expected);
+
+        let created: [glam::Mat3; 2] = buffer.create().unwrap();
+        assert_eq!(created, [expected; 2]);
+    }
+
+    #[test]
+    fn mat3a_same_bytes_as_mat3() {
+        let mat3 = glam::Mat3::from_cols_array(&[1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let mat3a = glam::Mat3A::from(mat3);
+
+        assert_eq!(glam::Mat3A::METADATA.alignment().get(), 16);
+        assert_eq!(glam::Mat3A::min_size().get(), 48);
+
+        let mut mat3_buffer = StorageBuffer::new(Vec::<u8>::new());
+        mat3_buffer.write(&mat3).unwrap();
+
+        let mut mat3a_buffer = StorageBuffer::new(Vec::<u8>::new());
+        mat3a_buffer.write(&mat3a).unwrap();
+
+        assert_eq!(mat3_buffer.as_ref(), mat3a_buffer.as_ref());
+
+        let created: glam::Mat3A = mat3_buffer.create().unwrap();
+        assert_eq!(created, mat3a);
+
+        let mut read = glam::Mat3A::ZERO;
+        mat3_buffer.read(&mut read).unwrap();
+        assert_eq!(read, mat3a);
+    }
+
+    #[test]
+    fn quat_array() {
+        use crate::ShaderSize;
+
+        assert_eq!(<[glam::Quat; 4]>::SHADER_SIZE.get(), 64);
+        assert_eq!(<[glam::Quat; 4]>::METADATA.stride().get(), 16);
+        assert!(<[glam::Quat; 4]>::METADATA.is_pod());
+
+        let quats = [
+            glam::Quat::IDENTITY,
+            glam::Quat::from_rotation_x(0.5),
+            glam::Quat::from_rotation_y(1.0),
+            glam::Quat::from_xyzw(0.1, 0.2, 0.3, 0.4),
+        ];
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&quats).unwrap();
+        assert_eq!(buffer.as_ref().len(), 64);
+
+        let vec4s: [glam::Vec4; 4] = buffer.create().unwrap();
+        for (vec4, quat) in vec4s.iter().zip(quats) {
+            assert_eq!(vec4.to_array(), quat.to_array());
+        }
+
+        let created: [glam::Quat; 4] = buffer.create().unwrap();
+        assert_eq!(created, quats);
+
+        let mut read = [glam::Quat::IDENTITY; 4];
+        buffer.read(&mut read).unwrap();
+        assert_eq!(read, quats);
+    }
+
+    #[test]
+    fn affine3a_round_trip() {
+        let affine = glam::Affine3A::from_scale_rotation_translation(
+            glam::Vec3::new(1., 2., 3.),
+            glam::Quat::from_rotation_y(0.5),
+            glam::Vec3::new(4., 5., 6.),
+        );
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&affine).unwrap();
+
+        let mat4: glam::Mat4 = buffer.create().unwrap();
+        assert_eq!(mat4, glam::Mat4::from(affine));
+
+        let created: glam::Affine3A = buffer.create().unwrap();
+        assert_eq!(created, affine);
+
+        // the bottom row of non-affine matrices is lost
+        let projection = glam::Mat4::perspective_rh(1., 1., 0.1, 100.);
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&projection).unwrap();
+
+        let created: glam::Affine3A = buffer.create().unwrap();
+        assert_ne!(glam::Mat4::from(created), projection);
+        assert_eq!(created, glam::Affine3A::from_mat4(projection));
+    }
+
+    #[test]
+    fn transposed() {
+        use crate::Transposed;
+
+        let mat = glam::Mat4::from_cols_array(&core::array::from_fn(|i| i as f32));
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&Transposed(mat)).unwrap();
+
+        let mut transposed_buffer = StorageBuffer::new(Vec::<u8>::new());
+        transposed_buffer.write(&mat.transpose()).unwrap();
+
+        assert_eq!(buffer.as_ref(), transposed_buffer.as_ref());
+
+        let created: Transposed<glam::Mat4> = buffer.create().unwrap();
+        assert_eq!(created.0, mat);
+
+        let mat = glam::Mat3::from_cols_array(&[1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&Transposed(mat)).unwrap();
+        assert_eq!(buffer.as_ref().len(), 48);
+
+        let written: glam::Mat3 = buffer.create().unwrap();
+        assert_eq!(written, mat.transpose());
+
+        let mut read = Transposed(glam::Mat3::ZERO);
+        buffer.read(&mut read).unwrap();
+        assert_eq!(read.0, mat);
+    }
+
+    #[test]
+    fn mat4_rows_cols() {
+        use crate::Transposed;
+
+        let mat = glam::Mat4::from_cols_array(&core::array::from_fn(|i| i as f32));
+
+        let mut mat_buffer = StorageBuffer::new(Vec::<u8>::new());
+        mat_buffer.write(&mat).unwrap();
+
+        let mut cols_buffer = StorageBuffer::new(Vec::<u8>::new());
+        cols_buffer.write(&super::mat4_cols(&mat)).unwrap();
+        assert_eq!(cols_buffer.as_ref().len(), 64);
+        assert_eq!(cols_buffer.as_ref(), mat_buffer.as_ref());
+
+        let mut transposed_buffer = StorageBuffer::new(Vec::<u8>::new());
+        transposed_buffer.write(&Transposed(mat)).unwrap();
+
+        let mut rows_buffer = StorageBuffer::new(Vec::<u8>::new());
+        rows_buffer.write(&super::mat4_rows(&mat)).unwrap();
+        assert_eq!(rows_buffer.as_ref().len(), 64);
+        assert_eq!(rows_buffer.as_ref(), transposed_buffer.as_ref());
+
+        let rows: [glam::Vec4; 4] = rows_buffer.create().unwrap();
+        assert_eq!(rows[1], glam::Vec4::new(1., 5., 9., 13.));
+    }
+
+    #[test]
+    fn bool_vectors() {
+        assert_eq!(glam::BVec3::METADATA.alignment().get(), 16);
+        assert_eq!(glam::BVec3::min_size().get(), 12);
+
+        let bvec = glam::BVec4::new(true, false, false, true);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&bvec).unwrap();
+
+        let lanes: glam::UVec4 = buffer.create().unwrap();
+        assert_eq!(lanes, glam::UVec4::new(1, 0, 0, 1));
+
+        let created: glam::BVec4 = buffer.create().unwrap();
+        assert_eq!(created, bvec);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&glam::UVec2::new(0, 7)).unwrap();
+
+        let mut read = glam::BVec2::TRUE;
+        buffer.read(&mut read).unwrap();
+        assert_eq!(read, glam::BVec2::new(false, true));
+    }
+
+    #[test]
+    fn bool_masks() {
+        assert_eq!(glam::BVec3A::METADATA.alignment().get(), 16);
+        assert_eq!(glam::BVec4A::min_size().get(), 16);
+
+        let mask = glam::Vec4::new(1., -1., 2., -2.).cmpgt(glam::Vec4::ZERO);
+        assert_eq!(mask.bitmask(), 0b0101);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&mask).unwrap();
+
+        let lanes: glam::UVec4 = buffer.create().unwrap();
+        assert_eq!(lanes, glam::UVec4::new(1, 0, 1, 0));
+
+        let created: glam::BVec4A = buffer.create().unwrap();
+        assert_eq!(created.bitmask(), mask.bitmask());
+
+        let mask = glam::Vec3A::new(0., 1., 2.).cmpge(glam::Vec3A::ONE);
+        assert_eq!(mask.bitmask(), 0b110);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&mask).unwrap();
+
+        let lanes: glam::UVec3 = buffer.create().unwrap();
+        assert_eq!(lanes, glam::UVec3::new(0, 1, 1));
+
+        let mut read = glam::BVec3A::TRUE;
+        buffer.read(&mut read).unwrap();
+        assert_eq!(read.bitmask(), 0b110);
+    }
+
+    #[test]
+    fn packed_vec3() {
+        use crate::{Packed, ShaderSize};
+
+        assert_eq!(glam::Vec3::min_size().get(), 12);
+        assert_eq!(glam::Vec3::METADATA.alignment().get(), 16);
+        assert_eq!(Packed::<glam::Vec3>::min_size().get(), 12);
+        assert_eq!(Packed::<glam::Vec3>::METADATA.alignment().get(), 4);
+        assert_eq!(<[Packed<glam::Vec3>; 4]>::SHADER_SIZE.get(), 48);
+        assert!(Packed::<glam::Vec3>::check_uniform_compat().is_err());
+
+        let array = [
+            Packed(glam::Vec3::new(1., 2., 3.)),
+            Packed(glam::Vec3::new(4., 5., 6.)),
+        ];
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&array).unwrap();
+
+        let floats: [f32; 6] = buffer.create().unwrap();
+        assert_eq!(floats, [1., 2., 3., 4., 5., 6.]);
+
+        let created: [Packed<glam::Vec3>; 2] = buffer.create().unwrap();
+        assert_eq!(created, array);
+
+        let mut read = [Packed(glam::Vec3A::ZERO); 2];
+        buffer.read(&mut read).unwrap();
+        assert_eq!(read[1].0, glam::Vec3A::new(4., 5., 6.));
+    }
+
+    #[test]
+    fn bitcast_vec4() {
+        use crate::Bitcast;
+
+        assert_eq!(Bitcast::<glam::Vec4>::METADATA.alignment().get(), 16);
+        assert_eq!(Bitcast::<glam::Vec3>::min_size().get(), 12);
+
+        let bits = [0x7FC0_1234, 0xFFFF_FFFF, 0x8000_0000, 0x0000_0001];
+        let value = Bitcast(glam::Vec4::from_array(bits.map(f32::from_bits)));
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&value).unwrap();
+
+        let lanes: glam::UVec4 = buffer.create().unwrap();
+        assert_eq!(lanes.to_array(), bits);
+
+        let created: Bitcast<glam::Vec4> = buffer.create().unwrap();
+        assert_eq!(created.0.to_array().map(f32::to_bits), bits);
+
+        let mut read = Bitcast(glam::Vec4::ZERO);
+        buffer.read(&mut read).unwrap();
+        assert_eq!(read.0.to_array().map(f32::to_bits), bits);
+    }
+}
@@ -0,0 +1,93 @@
+use core::num::NonZeroU64;
+
+use crate::core::{
+    BufferMut, CalculateSizeFor, Metadata, RuntimeSizedArray, ShaderSize, ShaderType,
+    UniformCompatError, WriteInto, Writer,
+};
+use crate::rts_array::{impl_rts_array, Length};
+use crate::types::array::ArrayMetadata;
+
+impl_rts_array!(indexmap::IndexSet<T, S>; (T, S); using len truncate);
+
+// only the values are laid out (in insertion order), keys are not part of the shader representation
+
+impl<K, V: ShaderType + ShaderSize, S> ShaderType for indexmap::IndexMap<K, V, S> {
+    type ExtraMetadata = ArrayMetadata;
+    const METADATA: Metadata<Self::ExtraMetadata> = <[V]>::METADATA;
+
+    const UNIFORM_COMPAT_CHECK: fn() -> Result<(), UniformCompatError> =
+        <[V]>::UNIFORM_COMPAT_CHECK;
+
+    fn size(&self) -> NonZeroU64 {
+        Self::calculate_size_for(self.len() as u64)
+    }
+}
+
+impl<K, V, S> Length for indexmap::IndexMap<K, V, S> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V: ShaderType + ShaderSize, S> RuntimeSizedArray for indexmap::IndexMap<K, V, S> {
+    fn len(&self) -> usize {
+        Length::length(self)
+    }
+}
+
+impl<K, V: ShaderType + ShaderSize, S> CalculateSizeFor for indexmap::IndexMap<K, V, S> {
+    fn calculate_size_for(nr_of_el: u64) -> NonZeroU64 {
+        <[V]>::calculate_size_for(nr_of_el)
+    }
+}
+
+impl<K, V: WriteInto + ShaderType + ShaderSize, S> WriteInto for indexmap::IndexMap<K, V, S> {
+    fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+        for value in self.values() {
+            WriteInto::write_into(value, writer);
+            writer.advance(Self::METADATA.el_padding() as usize);
+        }
+
+        if self.is_empty() {
+            writer.write_zeros(Self::METADATA.stride().get() as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ShaderType, StorageBuffer};
+    use indexmap::{IndexMap, IndexSet};
+
+    #[test]
+    fn index_set() {
+        let set: IndexSet<u32> = [5, 1, 4, 2].into_iter().collect();
+        assert_eq!(set.size().get(), 16);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&set).unwrap();
+        assert_eq!(
+            buffer.as_ref(),
+            &[5, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0]
+        );
+
+        let created: IndexSet<u32> = buffer.create().unwrap();
+        assert!(created.iter().eq(set.iter()));
+    }
+
+    #[test]
+    fn index_map_values() {
+        let mut map = IndexMap::new();
+        map.insert("c", 3u32);
+        map.insert("a", 1u32);
+        map.insert("b", 2u32);
+        assert_eq!(map.size().get(), 12);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&map).unwrap();
+        assert_eq!(buffer.as_ref(), &[3, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0]);
+
+        let values: Vec<u32> = buffer.create().unwrap();
+        assert_eq!(values, [3, 1, 2]);
+    }
+}
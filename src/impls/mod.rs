@@ -3,14 +3,20 @@ mod archery;
 #[cfg(feature = "static-rc")]
 mod static_rc;
 
+#[cfg(feature = "bevy_math")]
+mod bevy_math;
 #[cfg(feature = "cgmath")]
 mod cgmath;
 #[cfg(feature = "glam")]
 mod glam;
+#[cfg(feature = "glam")]
+pub use self::glam::{mat4_cols, mat4_rows};
 #[cfg(feature = "mint")]
 mod mint;
 #[cfg(feature = "nalgebra")]
 mod nalgebra;
+#[cfg(feature = "palette")]
+mod palette;
 #[cfg(feature = "ultraviolet")]
 mod ultraviolet;
 #[cfg(feature = "vek")]
@@ -18,13 +24,22 @@ mod vek;
 
 #[cfg(feature = "arrayvec")]
 mod arrayvec;
+#[cfg(feature = "indexmap")]
+mod indexmap;
 #[cfg(feature = "ndarray")]
 mod ndarray;
 #[cfg(feature = "smallvec")]
 mod smallvec;
+#[cfg(feature = "smallvec")]
+pub use self::smallvec::FixedSmallVec;
 #[cfg(feature = "tinyvec")]
 mod tinyvec;
 
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "fixed")]
+mod fixed;
+
 #[cfg(feature = "im")]
 mod im;
 #[cfg(feature = "im-rc")]
@@ -1,6 +1,12 @@
 use crate::{
+    core::{
+        BufferMut, BufferRef, CreateFrom, Metadata, ReadFrom, Reader, UniformCompatError,
+        WriteInto, Writer,
+    },
     matrix::{impl_matrix, AsMutMatrixParts, AsRefMatrixParts, FromMatrixParts, MatrixScalar},
+    types::array::ArrayMetadata,
     vector::{impl_vector, AsMutVectorParts, AsRefVectorParts, FromVectorParts, VectorScalar},
+    ShaderSize, ShaderType,
 };
 
 impl_vector!(2, nalgebra::VectorView2<'_, T>);
@@ -100,3 +106,79 @@ impl<T: MatrixScalar, const C: usize, const R: usize> FromMatrixParts<T, C, R>
         Self::from_array_storage(nalgebra::ArrayStorage(parts))
     }
 }
+
+// WGSL has no vectors with more than 4 components,
+// `SVector<T, N>` (where `N` is in 5..=16) is laid out as an `array<T, N>` instead
+macro_rules! impl_array_for_svector {
+    ($($n:literal),*) => {$(
+        impl<T: ShaderSize> ShaderType for nalgebra::SVector<T, $n> {
+            type ExtraMetadata = ArrayMetadata;
+            const METADATA: Metadata<Self::ExtraMetadata> = <[T; $n]>::METADATA.no_pod();
+
+            const UNIFORM_COMPAT_CHECK: fn() -> Result<(), UniformCompatError> =
+                <[T; $n]>::UNIFORM_COMPAT_CHECK;
+        }
+
+        impl<T: ShaderSize> ShaderSize for nalgebra::SVector<T, $n> {}
+
+        impl<T: ShaderSize + WriteInto> WriteInto for nalgebra::SVector<T, $n>
+        where
+            Self: AsRef<[T; $n]>,
+        {
+            #[inline]
+            fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+                WriteInto::write_into(self.as_ref(), writer)
+            }
+        }
+
+        impl<T: ShaderSize + ReadFrom> ReadFrom for nalgebra::SVector<T, $n>
+        where
+            Self: AsMut<[T; $n]>,
+        {
+            #[inline]
+            fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+                ReadFrom::read_from(self.as_mut(), reader)
+            }
+        }
+
+        impl<T: ShaderSize + CreateFrom> CreateFrom for nalgebra::SVector<T, $n> {
+            #[inline]
+            fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+                let parts = <[T; $n] as CreateFrom>::create_from(reader);
+                Self::from_array_storage(nalgebra::ArrayStorage([parts]))
+            }
+        }
+    )*};
+}
+
+impl_array_for_svector!(5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+
+#[cfg(test)]
+mod test {
+    use crate::{ShaderType, StorageBuffer};
+
+    #[test]
+    fn svector_6_as_array() {
+        type Twist = nalgebra::SVector<f32, 6>;
+
+        assert_eq!(Twist::METADATA.alignment().get(), 4);
+        assert_eq!(Twist::min_size().get(), 24);
+        assert!(Twist::check_uniform_compat().is_err());
+
+        let twist = Twist::from_column_slice(&[1., 2., 3., 4., 5., 6.]);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&twist).unwrap();
+
+        let mut expected = StorageBuffer::new(Vec::<u8>::new());
+        expected.write(&[1f32, 2., 3., 4., 5., 6.]).unwrap();
+        assert_eq!(buffer.as_ref(), expected.as_ref());
+
+        let created: Twist = buffer.create().unwrap();
+        assert_eq!(created, twist);
+
+        let mut read = Twist::zeros();
+        buffer.read(&mut read).unwrap();
+        assert_eq!(read, twist);
+    }
+}
@@ -1,3 +1,81 @@
+use crate::core::{BufferRef, ReadFrom, Reader, ShaderType};
 use crate::rts_array::impl_rts_array;
+use crate::types::array::ArrayMetadata;
 
+// elements are laid out in logical order (regardless of the memory layout, views with strides are supported)
 impl_rts_array!(ndarray::ArrayBase<S, D>; (T, S: ndarray::RawData<Elem = T>, D: ndarray::Dimension); using len);
+
+/// Reads up to `self.len()` elements in place (in logical order)
+impl<T, S, D> ReadFrom for ndarray::ArrayBase<S, D>
+where
+    T: ReadFrom,
+    S: ndarray::DataMut<Elem = T>,
+    D: ndarray::Dimension,
+    Self: ShaderType<ExtraMetadata = ArrayMetadata>,
+{
+    fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+        let max = reader
+            .ctx
+            .rts_array_max_el_to_read
+            .take()
+            .unwrap_or(u32::MAX) as usize;
+        let count = max.min(reader.remaining() / Self::METADATA.stride().get() as usize);
+
+        for item in self.iter_mut().take(count) {
+            ReadFrom::read_from(item, reader);
+            reader.advance(Self::METADATA.el_padding() as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ShaderType, StorageBuffer};
+    use ndarray::{s, Array1, ArrayView1};
+
+    #[test]
+    fn array1() {
+        let array = Array1::from(vec![1u32, 2, 3]);
+        assert_eq!(array.size().get(), 12);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&array).unwrap();
+        assert_eq!(buffer.as_ref(), &[1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0]);
+
+        let created: Array1<u32> = buffer.create().unwrap();
+        assert_eq!(created, array);
+
+        let mut read = Array1::zeros(2);
+        buffer.read(&mut read).unwrap();
+        assert_eq!(read, Array1::from(vec![1, 2]));
+    }
+
+    #[test]
+    fn array_view1() {
+        let data = [1u32, 2, 3, 4, 5, 6];
+
+        let contiguous = ArrayView1::from(&data[..]);
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&contiguous).unwrap();
+        let created: Vec<u32> = buffer.create().unwrap();
+        assert_eq!(created, data);
+
+        let strided = contiguous.slice(s![1..;2]);
+        assert_eq!(strided.size().get(), 12);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&strided).unwrap();
+        assert_eq!(buffer.as_ref(), &[2, 0, 0, 0, 4, 0, 0, 0, 6, 0, 0, 0]);
+
+        let reversed = contiguous.slice(s![..;-1]);
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&reversed).unwrap();
+        let created: Array1<u32> = buffer.create().unwrap();
+        assert_eq!(created, Array1::from(vec![6, 5, 4, 3, 2, 1]));
+
+        let mut data = [0u32; 6];
+        let mut view = ndarray::ArrayViewMut1::from(&mut data[..]);
+        buffer.read(&mut view.slice_mut(s![..;2])).unwrap();
+        assert_eq!(data, [6, 0, 5, 0, 4, 0]);
+    }
+}
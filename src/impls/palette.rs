@@ -0,0 +1,109 @@
+use crate::{
+    core::{
+        AlignmentValue, BufferMut, BufferRef, CreateFrom, Metadata, ReadFrom, Reader, SizeValue,
+        WriteInto, Writer,
+    },
+    ShaderSize, ShaderType,
+};
+use palette::rgb::{Rgb, Rgba};
+
+// Colors are laid out as `vec3<f32>`s and `vec4<f32>`s of their raw components,
+// no color space conversion happens (e.g. an `Srgb` is written as is, not linearized).
+macro_rules! impl_color {
+    ($n:literal, $type:ty, ($($component:ident),*)) => {
+        impl<S> ShaderType for $type {
+            type ExtraMetadata = ();
+            const METADATA: Metadata<Self::ExtraMetadata> = {
+                let size = SizeValue::new(4 * $n);
+
+                Metadata {
+                    alignment: AlignmentValue::from_next_power_of_two_size(size),
+                    has_uniform_min_alignment: false,
+                    min_size: size,
+                    is_pod: false,
+                    extra: (),
+                }
+            };
+        }
+
+        impl<S> ShaderSize for $type {}
+
+        impl<S> WriteInto for $type {
+            #[inline]
+            fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+                let ($($component,)*) = (*self).into_components();
+                WriteInto::write_into(&[$($component),*], writer);
+            }
+        }
+
+        impl<S> ReadFrom for $type {
+            #[inline]
+            fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+                *self = CreateFrom::create_from(reader);
+            }
+        }
+
+        impl<S> CreateFrom for $type {
+            #[inline]
+            fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+                let [$($component),*]: [f32; $n] = CreateFrom::create_from(reader);
+                Self::from_components(($($component,)*))
+            }
+        }
+    };
+}
+
+impl_color!(3, Rgb<S, f32>, (red, green, blue));
+impl_color!(4, Rgba<S, f32>, (red, green, blue, alpha));
+
+#[cfg(test)]
+mod test {
+    use crate::{ShaderType, StorageBuffer};
+    use palette::{LinSrgb, LinSrgba, Srgb, Srgba};
+
+    #[test]
+    fn rgb_round_trip() {
+        assert_eq!(Srgb::<f32>::min_size().get(), 12);
+        assert_eq!(Srgb::<f32>::METADATA.alignment().get(), 16);
+
+        let color = Srgb::new(0.25, 0.5, 1.0);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&color).unwrap();
+
+        let components: [f32; 3] = buffer.create().unwrap();
+        assert_eq!(components, [0.25, 0.5, 1.0]);
+
+        let created: Srgb = buffer.create().unwrap();
+        assert_eq!(created, color);
+
+        // no conversion, the components are reinterpreted as linear
+        let mut read = LinSrgb::new(0.0, 0.0, 0.0);
+        buffer.read(&mut read).unwrap();
+        assert_eq!(read, LinSrgb::new(0.25, 0.5, 1.0));
+    }
+
+    #[test]
+    fn rgba_round_trip() {
+        assert_eq!(LinSrgba::<f32>::min_size().get(), 16);
+        assert_eq!(LinSrgba::<f32>::METADATA.alignment().get(), 16);
+
+        let colors = [
+            LinSrgba::new(0.1, 0.2, 0.3, 0.4),
+            LinSrgba::new(0.5, 0.6, 0.7, 0.8),
+        ];
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&colors).unwrap();
+        assert_eq!(buffer.as_ref().len(), 32);
+
+        let components: [[f32; 4]; 2] = buffer.create().unwrap();
+        assert_eq!(components, [[0.1, 0.2, 0.3, 0.4], [0.5, 0.6, 0.7, 0.8]]);
+
+        let created: [LinSrgba; 2] = buffer.create().unwrap();
+        assert_eq!(created, colors);
+
+        let created: [Srgba; 2] = buffer.create().unwrap();
+        assert_eq!(created[1].into_components(), (0.5, 0.6, 0.7, 0.8));
+    }
+}
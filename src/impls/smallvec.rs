@@ -1,4 +1,152 @@
-use crate::rts_array::impl_rts_array;
+use core::ops::{Deref, DerefMut};
+
+use smallvec::SmallVec;
+
+use crate::{
+    core::{
+        BufferMut, BufferRef, CreateFrom, Metadata, ReadFrom, Reader, UniformCompatError,
+        WriteInto, Writer,
+    },
+    rts_array::impl_rts_array,
+    types::array::ArrayMetadata,
+    ShaderSize, ShaderType,
+};
 
 // softcap
 impl_rts_array!(smallvec::SmallVec<A>; (T, A: smallvec::Array<Item = T>); using len truncate);
+
+/// Wrapper around a [`SmallVec`] that always contains exactly `N` elements
+///
+/// Unlike [`SmallVec`] (which is a runtime-sized array), this type is equivalent to `[T; N]`
+/// (a fixed-footprint type) and can be used in uniform buffers
+///
+/// # Examples
+///
+/// ```
+/// # use encase::FixedSmallVec;
+/// # use smallvec::smallvec;
+/// let fixed = FixedSmallVec::<u32, 2>::try_from(smallvec![1, 2]).unwrap();
+/// assert_eq!(&*fixed, &[1, 2]);
+///
+/// assert!(FixedSmallVec::<u32, 2>::try_from(smallvec![1, 2, 3]).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedSmallVec<T, const N: usize>(SmallVec<[T; N]>);
+
+impl<T, const N: usize> FixedSmallVec<T, N> {
+    pub fn into_inner(self) -> SmallVec<[T; N]> {
+        self.0
+    }
+
+    fn as_array(&self) -> &[T; N] {
+        self.0.as_slice().try_into().unwrap()
+    }
+
+    fn as_mut_array(&mut self) -> &mut [T; N] {
+        self.0.as_mut_slice().try_into().unwrap()
+    }
+}
+
+/// Fails (giving back the given [`SmallVec`]) if its length is not `N`
+impl<T, const N: usize> TryFrom<SmallVec<[T; N]>> for FixedSmallVec<T, N> {
+    type Error = SmallVec<[T; N]>;
+
+    fn try_from(vec: SmallVec<[T; N]>) -> Result<Self, Self::Error> {
+        if vec.len() == N {
+            Ok(Self(vec))
+        } else {
+            Err(vec)
+        }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for FixedSmallVec<T, N> {
+    fn from(array: [T; N]) -> Self {
+        Self(SmallVec::from_buf(array))
+    }
+}
+
+impl<T, const N: usize> Deref for FixedSmallVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> DerefMut for FixedSmallVec<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: ShaderSize, const N: usize> ShaderType for FixedSmallVec<T, N> {
+    type ExtraMetadata = ArrayMetadata;
+    const METADATA: Metadata<Self::ExtraMetadata> = <[T; N]>::METADATA.no_pod();
+
+    const UNIFORM_COMPAT_CHECK: fn() -> Result<(), UniformCompatError> =
+        <[T; N]>::UNIFORM_COMPAT_CHECK;
+}
+
+impl<T: ShaderSize, const N: usize> ShaderSize for FixedSmallVec<T, N> {}
+
+impl<T: ShaderSize + WriteInto, const N: usize> WriteInto for FixedSmallVec<T, N> {
+    #[inline]
+    fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+        WriteInto::write_into(self.as_array(), writer)
+    }
+}
+
+impl<T: ShaderSize + ReadFrom, const N: usize> ReadFrom for FixedSmallVec<T, N> {
+    #[inline]
+    fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+        ReadFrom::read_from(self.as_mut_array(), reader)
+    }
+}
+
+impl<T: ShaderSize + CreateFrom, const N: usize> CreateFrom for FixedSmallVec<T, N> {
+    #[inline]
+    fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+        Self::from(<[T; N] as CreateFrom>::create_from(reader))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FixedSmallVec;
+    use smallvec::{smallvec, SmallVec};
+
+    #[test]
+    #[cfg(feature = "mint")]
+    fn fixed_small_vec() {
+        use crate::{ShaderSize, ShaderType, UniformBuffer};
+
+        type Fixed = FixedSmallVec<mint::Vector4<f32>, 2>;
+
+        assert_eq!(Fixed::SHADER_SIZE.get(), 32);
+        Fixed::assert_uniform_compat();
+
+        let v = mint::Vector4::from([1.0, 2.0, 3.0, 4.0]);
+        let fixed = Fixed::try_from(smallvec![v, v]).unwrap();
+
+        let mut buffer = UniformBuffer::new(Vec::<u8>::new());
+        buffer.write(&fixed).unwrap();
+
+        let mut expected = UniformBuffer::new(Vec::<u8>::new());
+        expected.write(&[v, v]).unwrap();
+        assert_eq!(buffer.as_ref(), expected.as_ref());
+
+        let created: Fixed = buffer.create().unwrap();
+        assert_eq!(created, fixed);
+    }
+
+    #[test]
+    fn fixed_small_vec_wrong_length() {
+        let vec: SmallVec<[u32; 4]> = smallvec![1, 2, 3];
+        let vec = FixedSmallVec::<u32, 4>::try_from(vec).unwrap_err();
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+
+        let vec: SmallVec<[u32; 4]> = smallvec![1, 2, 3, 4, 5];
+        assert!(FixedSmallVec::<u32, 4>::try_from(vec).is_err());
+    }
+}
@@ -1,7 +1,15 @@
-use crate::rts_array::impl_rts_array;
+use crate::rts_array::{impl_rts_array, Length};
 
-// hardcap
-impl_rts_array!(tinyvec::ArrayVec<A>; (T, A: tinyvec::Array<Item = T>); using len truncate);
+// hardcap (reads stop after `A::CAPACITY` elements)
+impl_rts_array!(tinyvec::ArrayVec<A>; (T, A: tinyvec::Array<Item = T>); using truncate);
+
+impl<A: tinyvec::Array> Length for tinyvec::ArrayVec<A> {
+    const CAPACITY: Option<usize> = Some(A::CAPACITY);
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
 
 // softcap
 impl_rts_array!(tinyvec::TinyVec<A>; (T, A: tinyvec::Array<Item = T>); using len truncate);
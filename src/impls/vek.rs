@@ -7,6 +7,10 @@ impl_vector!(2, vek::Vec2<T>; using From);
 impl_vector!(3, vek::Vec3<T>; using From);
 impl_vector!(4, vek::Vec4<T>; using From);
 
+// `vek::MatN` are the column-major `repr_c` matrices (`as_col_slice`/`from_col_arrays` only exist for those),
+// the row-major ones (`vek::mat::repr_c::row_major`) are intentionally not supported
+// since their memory layout doesn't match WGSL's
+
 impl_matrix!(2, 2, vek::Mat2<T>);
 impl_matrix!(3, 3, vek::Mat3<T>);
 impl_matrix!(4, 4, vek::Mat4<T>);
@@ -55,3 +59,46 @@ macro_rules! impl_matrix_traits {
 impl_matrix_traits!(2, 2, vek::Mat2<T>);
 impl_matrix_traits!(3, 3, vek::Mat3<T>);
 impl_matrix_traits!(4, 4, vek::Mat4<T>);
+
+#[cfg(test)]
+mod test {
+    use crate::StorageBuffer;
+
+    fn to_floats(bytes: &[u8]) -> Vec<f32> {
+        bytes
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn mat2_is_written_column_major() {
+        // `new` takes the elements in row-major (visual) order
+        let mat = vek::Mat2::new(1., 2., 3., 4.);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&mat).unwrap();
+
+        assert_eq!(to_floats(buffer.as_ref()), [1., 3., 2., 4.]);
+
+        let created: vek::Mat2<f32> = buffer.create().unwrap();
+        assert_eq!(created, mat);
+    }
+
+    #[test]
+    fn mat3_is_written_column_major() {
+        let mat = vek::Mat3::from_col_arrays([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+        assert_eq!(mat.cols.x, vek::Vec3::new(1., 2., 3.));
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&mat).unwrap();
+
+        assert_eq!(
+            to_floats(buffer.as_ref()),
+            [1., 2., 3., 0., 4., 5., 6., 0., 7., 8., 9., 0.]
+        );
+
+        let created: vek::Mat3<f32> = buffer.create().unwrap();
+        assert_eq!(created, mat);
+    }
+}
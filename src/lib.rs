@@ -19,30 +19,106 @@
     html_logo_url = "https://raw.githubusercontent.com/teoxoy/encase/3d6d2e4d7670863e97463a15ceeafac6d13ee73e/logo.svg"
 )]
 
-/// Used to implement `ShaderType` for structs
+/// Used to implement `ShaderType` for structs and enums
 ///
 /// # Attributes
 ///
+/// Struct attributes
+///
+/// - `#[shader(layout = "std140")]`
+///
+///     Lays out the struct following the std140 rules
+///     (see the [uniform address space layout constraints](https://gpuweb.github.io/gpuweb/wgsl/#address-space-layout-constraints)):
+///     the alignment of the struct and of its struct and array fields is rounded up to 16
+///     and so is the space occupied by those fields,
+///     other fields keep their own alignment (e.g. a `vec2<f32>` following an `f32` is placed at offset 8)
+///
+///     It can't change the stride of array fields, arrays whose stride is not a multiple of 16
+///     (e.g. `[f32; 4]`) are rejected at compile time
+///
+/// - `#[shader(generate_repr_c)]`
+///
+///     Generates a `#[repr(C)]` struct named `{name}Gpu` containing the same fields
+///     each followed by a `_padN: [u8; X]` field (where `X` is the padding after field `N`)
+///     plus a `From<{name}>` impl, which can be used for zero-copy uploads (e.g. via `bytemuck`)
+///
+///     Skipped fields are left out, it can't be used on generic structs or structs containing a runtime-sized array
+///     and its in-memory size must match the shader size (which is checked at compile time)
+///
 /// Field attributes
 ///
+/// _They can be grouped in a `shader` attribute (preferred) like `#[shader(align(16), size(32))]`
+/// or used on their own like `#[align(16)]`_
+///
 /// - `#[align(X)]` where `X` is a power of 2 [`u32`] literal (equivalent to [WGSL align attribute](https://gpuweb.github.io/gpuweb/wgsl/#attribute-align))
 ///
-///     Used to increase the alignment of the field
+///     Used to increase the alignment of the field (up to 2^31, e.g. `#[align(4096)]` for page-aligned fields)
 ///
 /// - `#[size(X)]` where `X` is a [`u32`] literal (equivalent to [WGSL size attribute](https://gpuweb.github.io/gpuweb/wgsl/#attribute-size))
 ///
-///     Used to increase the size of the field
+///     Used to increase the size of the field (`X` must be at least the size of the field's type)
 ///
-/// - `#[size(runtime)]` can only be attached to the last field of the struct
+/// - `#[size(runtime)]` can only be attached to the last field of the struct that isn't skipped
+///   (and can't be combined with `#[size(X)]`)
 ///
 ///     Used to denote the fact that the field it is attached to is a runtime-sized array
 ///
+/// - `#[shader(skip)]` (only available in the grouped form)
+///
+///     Excludes the field from the shader representation (useful for markers like [`PhantomData`](core::marker::PhantomData)),
+///     the field is left untouched when reading and is set to its [`Default`] value when creating
+///
+/// # Errors about unsatisfied trait bounds
+///
+/// The derive macro checks the type of each field, the note pointing at the function
+/// whose bound is not satisfied explains what is missing
+///
+/// - `field_type_must_implement_shader_type` - the type doesn't implement [`ShaderType`]
+///   (types from math libraries require their corresponding feature to be enabled)
+///
+/// - `field_must_have_a_fixed_size_unless_last_and_marked_with_size_runtime` - the type is runtime-sized
+///   (e.g. a [`Vec`]) but only the last field can be and it needs to be marked with `#[size(runtime)]`
+///
+/// - `field_marked_with_size_runtime_must_be_a_runtime_sized_array` - the field is marked with `#[size(runtime)]`
+///   but its type is not a runtime-sized array
+///
+/// # Unsupported types
+///
+/// `u64` and `i64` (and `glam`'s `U64VecN` and `I64VecN`) are not host-shareable types in WGSL, using them in a field
+/// results in a compile-time error (when the struct is written, read or its size is taken) suggesting a 32-bit replacement (e.g. `[u32; 2]` for a `u64`)
+///
+/// # Enums
+///
+/// Enums are laid out as a tagged union, the equivalent of `struct { tag: u32, payload: array<u32, N> }`
+/// where `tag` is the discriminant of the variant and the payload takes up as much space as the biggest variant
+///
+/// Explicit discriminants (e.g. `enum Mode { A = 1, B = 4 }`) are written as the tag (cast to a `u32`, like `Mode::B as u32`),
+/// variants without one follow the usual rules (the first variant is `0`, the others are one more than the previous variant)
+///
+/// Each variant can have at most one field (group multiple fields in a struct), the field's type
+/// needs to implement [`ShaderSize`]. The payload starts at the first offset after the tag that satisfies
+/// the alignment of all variants, the space after a smaller variant's payload is padding
+/// and is skipped over when writing and reading (unless zeroing padding is enabled)
+///
+/// The `#[align(X)]`, `#[size(X)]` and `#[shader(..)]` attributes can't be used on the fields of variants
+///
+/// WGSL has no unions, the shader side will have to reinterpret the payload based on the tag
+/// (e.g. via `bitcast`). [`ShaderType::type_layout`] describes the enum as the struct above
+///
+/// **Creating or reading a value with a tag that doesn't match any variant panics**
+/// (instead of returning an error through the buffer wrappers), if the data comes from an untrusted source
+/// (e.g. it was written by a shader) check the tag first by creating a `u32` from the start of the value
+///
 /// # Note about generics
 ///
 /// While structs using generic type parameters are supported by this derive macro
 ///
-/// - the `#[align(X)]` and `#[size(X)]` attributes will only work
-///   if they are attached to fields whose type contains no generic type parameters
+/// - the `#[align(X)]` and `#[size(X)]` attributes are only checked against the alignment and size of the field's type
+///   when the struct is used if that type contains generic type or const parameters
+///   (and so is the stride of array fields of `std140` structs)
+///
+/// - fields whose type depends on a const parameter (e.g. `[T; N]`) are laid out
+///   once the parameters are known, the metadata of `[T; N]` is computed for each `N` the struct is used with
 ///
 /// # Examples
 ///
@@ -74,6 +150,19 @@
 /// }
 /// ```
 ///
+/// Tagged union
+///
+/// ```
+/// # use mint;
+/// # use crate::encase::ShaderType;
+/// #[derive(ShaderType)]
+/// enum Light {
+///     Off,
+///     Point(mint::Vector3<f32>),
+///     Spot { direction: mint::Vector4<f32> },
+/// }
+/// ```
+///
 /// Complex
 ///
 /// ```
@@ -102,18 +191,36 @@ mod types;
 mod impls;
 
 pub use crate::core::{
-    CalculateSizeFor, DynamicStorageBuffer, DynamicUniformBuffer, ShaderSize, ShaderType,
-    StorageBuffer, UniformBuffer,
+    from_bytes, to_vec, verify_layout, write_chunked, ArrayReader, CalculateSizeFor,
+    DynamicStorageBuffer, DynamicUniformBuffer, FieldLayout, LayoutMismatch, ShaderSize,
+    ShaderType, StorageBuffer, TypeLayout, TypeLayoutKind, UniformBuffer,
 };
+pub use types::array::{AlignedArray, ArrayStride};
+pub use types::canonical::Canonical;
+pub use types::matrix::{NonSquareMat, Transposed};
+pub use types::packed_vec4::PackedVec4;
 pub use types::runtime_sized_array::ArrayLength;
+pub use types::vector::{Bitcast, Packed};
+
+#[cfg(feature = "smallvec")]
+pub use impls::FixedSmallVec;
+#[cfg(feature = "glam")]
+pub use impls::{mat4_cols, mat4_rows};
 
+/// Module containing the items needed to implement `ShaderType` (and the read/write traits) by hand
 pub mod internal {
     pub use super::core::{
-        AlignmentValue, BufferMut, BufferRef, CreateFrom, EnlargeError, Error, ReadContext,
-        ReadFrom, Reader, Result, SizeValue, WriteContext, WriteInto, Writer,
+        AlignmentValue, BufferMut, BufferRef, CreateFrom, EnlargeError, Error, Metadata,
+        ReadContext, ReadFrom, Reader, Result, SizeValue, UniformCompatError, WriteContext,
+        WriteInto, Writer,
     };
 }
 
+/// Module containing helpers for writing data stored as a structure of arrays
+pub mod soa {
+    pub use super::core::write2;
+}
+
 /// Module containing items necessary to implement `ShaderType` for runtime-sized arrays
 pub mod rts_array {
     #[doc(inline)]
@@ -147,11 +254,15 @@ pub mod private {
     pub use super::core::BufferMut;
     pub use super::core::BufferRef;
     pub use super::core::CreateFrom;
+    pub use super::core::FieldLayout;
     pub use super::core::Metadata;
     pub use super::core::ReadFrom;
     pub use super::core::Reader;
     pub use super::core::RuntimeSizedArray;
     pub use super::core::SizeValue;
+    pub use super::core::TypeLayout;
+    pub use super::core::TypeLayoutKind;
+    pub use super::core::UniformCompatError;
     pub use super::core::WriteInto;
     pub use super::core::Writer;
     pub use super::types::array::ArrayMetadata;
@@ -159,7 +270,6 @@ pub mod private {
     pub use super::types::r#struct::StructMetadata;
     pub use super::types::runtime_sized_array::{ArrayLength, Length, Truncate};
     pub use super::types::vector::*;
-    pub use super::utils::consume_zsts;
     pub use super::CalculateSizeFor;
     pub use super::ShaderSize;
     pub use super::ShaderType;
@@ -1,6 +1,6 @@
 use crate::core::{
-    BufferMut, BufferRef, CreateFrom, Metadata, ReadFrom, Reader, ShaderSize, ShaderType,
-    SizeValue, WriteInto, Writer,
+    AlignmentValue, BufferMut, BufferRef, CreateFrom, Metadata, ReadFrom, Reader, ShaderSize,
+    ShaderType, SizeValue, TypeLayout, TypeLayoutKind, UniformCompatError, WriteInto, Writer,
 };
 
 use core::mem::{size_of, MaybeUninit};
@@ -20,6 +20,31 @@ impl Metadata<ArrayMetadata> {
     }
 }
 
+/// Exposes the stride and per-element padding of array types
+///
+/// Elements are placed at multiples of their alignment, a `vec3<u32>` for example
+/// is 12 bytes in size but has an alignment of 16 resulting in a stride of 16 (and 4 bytes of padding after each element)
+///
+/// # Examples
+///
+/// ```
+/// # use encase::ArrayStride;
+/// assert_eq!(<[mint::Vector3<u32>; 4]>::STRIDE, 16);
+/// assert_eq!(<[mint::Vector3<u32>; 4]>::EL_PADDING, 4);
+/// ```
+pub trait ArrayStride {
+    /// Distance (in bytes) between the start of consecutive elements
+    const STRIDE: u64;
+
+    /// Padding (in bytes) after each element
+    const EL_PADDING: u64;
+}
+
+impl<T: ?Sized + ShaderType<ExtraMetadata = ArrayMetadata>> ArrayStride for T {
+    const STRIDE: u64 = T::METADATA.stride().get();
+    const EL_PADDING: u64 = T::METADATA.el_padding();
+}
+
 impl<T: ShaderType + ShaderSize, const N: usize> ShaderType for [T; N] {
     type ExtraMetadata = ArrayMetadata;
     const METADATA: Metadata<Self::ExtraMetadata> = {
@@ -43,21 +68,33 @@ impl<T: ShaderType + ShaderSize, const N: usize> ShaderType for [T; N] {
         }
     };
 
-    const UNIFORM_COMPAT_ASSERT: fn() = || {
-        crate::utils::consume_zsts([
-            <T as ShaderType>::UNIFORM_COMPAT_ASSERT(),
-            if let Some(min_alignment) = Self::METADATA.uniform_min_alignment() {
-                const_panic::concat_assert!(
-                    min_alignment.is_aligned(Self::METADATA.stride().get()),
-                    "array stride must be a multiple of ",
-                    min_alignment.get(),
-                    " (current stride: ",
-                    Self::METADATA.stride().get(),
-                    ")"
-                );
-            },
-        ]);
+    const UNIFORM_COMPAT_CHECK: fn() -> Result<(), UniformCompatError> = || {
+        <T as ShaderType>::UNIFORM_COMPAT_CHECK()?;
+        if let Some(min_alignment) = Self::METADATA.uniform_min_alignment() {
+            let stride = Self::METADATA.stride().get();
+            if !min_alignment.is_aligned(stride) {
+                return Err(UniformCompatError::ArrayStride {
+                    ty: core::any::type_name::<Self>(),
+                    field: None,
+                    min_alignment: min_alignment.get(),
+                    stride,
+                });
+            }
+        }
+        Ok(())
     };
+
+    fn type_layout() -> TypeLayout {
+        TypeLayout {
+            alignment: Self::METADATA.alignment().get(),
+            size: Self::METADATA.min_size().get(),
+            kind: TypeLayoutKind::Array {
+                element: Box::new(T::type_layout()),
+                stride: Self::METADATA.stride().get(),
+                len: Some(N as u64),
+            },
+        }
+    }
 }
 
 impl<T: ShaderSize, const N: usize> ShaderSize for [T; N] {}
@@ -68,7 +105,7 @@ where
 {
     #[inline]
     fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
-        if_pod_and_little_endian!(if pod_and_little_endian {
+        if_pod_and_little_endian!(writer: writer; if pod_and_little_endian {
             let ptr = self.as_ptr() as *const u8;
             let byte_slice: &[u8] = unsafe { core::slice::from_raw_parts(ptr, size_of::<Self>()) };
             writer.write_slice(byte_slice);
@@ -125,3 +162,119 @@ where
         })
     }
 }
+
+/// Fixed-size array whose alignment is increased to `A` (the array analog of the `#[align(X)]` field attribute)
+///
+/// The layout of the elements (and the size of the array) is the same as `[T; N]`'s,
+/// only the offset at which the array is placed (e.g. as a struct field) is affected
+///
+/// `A` must be a power of 2 and at least the alignment of `T` (checked at compile time)
+///
+/// # Examples
+///
+/// ```
+/// # use encase::{AlignedArray, ShaderType};
+/// #[derive(ShaderType)]
+/// struct Data {
+///     a: u32,
+///     b: AlignedArray<u32, 2, 16>,
+/// }
+///
+/// assert_eq!(Data::METADATA.offset(1), 16);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AlignedArray<T, const N: usize, const A: usize>(pub [T; N]);
+
+impl<T: ShaderType + ShaderSize, const N: usize, const A: usize> ShaderType
+    for AlignedArray<T, N, A>
+{
+    type ExtraMetadata = ArrayMetadata;
+    const METADATA: Metadata<Self::ExtraMetadata> = {
+        let alignment = AlignmentValue::new(A as u64);
+        let el_alignment = T::METADATA.alignment().get();
+        const_panic::concat_assert!(
+            alignment.get() >= el_alignment,
+            "AlignedArray alignment must be at least ",
+            el_alignment,
+            " (element's type alignment)"
+        );
+
+        Metadata {
+            alignment,
+            has_uniform_min_alignment: true,
+            min_size: <[T; N]>::METADATA.min_size(),
+            is_pod: <[T; N]>::METADATA.is_pod(),
+            extra: <[T; N]>::METADATA.extra,
+        }
+    };
+
+    const UNIFORM_COMPAT_CHECK: fn() -> Result<(), UniformCompatError> =
+        <[T; N]>::UNIFORM_COMPAT_CHECK;
+}
+
+impl<T: ShaderSize, const N: usize, const A: usize> ShaderSize for AlignedArray<T, N, A> {}
+
+impl<T: WriteInto, const N: usize, const A: usize> WriteInto for AlignedArray<T, N, A>
+where
+    [T; N]: ShaderType<ExtraMetadata = ArrayMetadata>,
+{
+    #[inline]
+    fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+        WriteInto::write_into(&self.0, writer);
+    }
+}
+
+impl<T: ReadFrom, const N: usize, const A: usize> ReadFrom for AlignedArray<T, N, A>
+where
+    [T; N]: ShaderType<ExtraMetadata = ArrayMetadata>,
+{
+    #[inline]
+    fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+        ReadFrom::read_from(&mut self.0, reader);
+    }
+}
+
+impl<T: CreateFrom, const N: usize, const A: usize> CreateFrom for AlignedArray<T, N, A>
+where
+    [T; N]: ShaderType<ExtraMetadata = ArrayMetadata>,
+{
+    #[inline]
+    fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+        AlignedArray(CreateFrom::create_from(reader))
+    }
+}
+
+#[cfg(test)]
+mod aligned_array {
+    use super::AlignedArray;
+    use crate::{ShaderSize, ShaderType, StorageBuffer};
+
+    #[test]
+    fn metadata() {
+        type Aligned = AlignedArray<u32, 4, 16>;
+
+        assert_eq!(Aligned::METADATA.alignment().get(), 16);
+        assert_eq!(<[u32; 4]>::METADATA.alignment().get(), 4);
+        assert_eq!(Aligned::SHADER_SIZE.get(), 16);
+        assert_eq!(Aligned::METADATA.stride().get(), 4);
+        assert!(Aligned::METADATA.is_pod());
+
+        assert_eq!(<AlignedArray<u32, 3, 256>>::METADATA.alignment().get(), 256);
+        assert_eq!(<AlignedArray<u32, 3, 256>>::SHADER_SIZE.get(), 12);
+        assert_eq!(<[AlignedArray<u32, 3, 16>; 2]>::METADATA.stride().get(), 16);
+    }
+
+    #[test]
+    fn round_trip() {
+        let array = AlignedArray::<u32, 4, 16>([1, 2, 3, 4]);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&array).unwrap();
+
+        let plain: [u32; 4] = buffer.create().unwrap();
+        assert_eq!(plain, [1, 2, 3, 4]);
+
+        let created: AlignedArray<u32, 4, 16> = buffer.create().unwrap();
+        assert_eq!(created, array);
+    }
+}
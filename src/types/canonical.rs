@@ -0,0 +1,130 @@
+use core::num::NonZeroU64;
+
+use crate::core::{
+    BufferMut, BufferRef, CreateFrom, Metadata, ReadFrom, Reader, ShaderSize, ShaderType,
+    TypeLayout, UniformCompatError, WriteInto, Writer,
+};
+
+/// Wrapper that writes the `f32`s contained in a value (scalars, vectors, matrices, arrays, struct fields)
+/// in a canonical form, NaNs as the quiet NaN `0x7FC00000` and `-0.0` as `0.0`
+///
+/// Equal values (as compared with `==`, NaNs aside) always result in the same bytes,
+/// useful for hashing or comparing the written buffers (e.g. for caching).
+/// Reads are left as they are
+///
+/// # Examples
+///
+/// ```
+/// # use encase::{Canonical, StorageBuffer};
+/// let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+/// buffer.write(&Canonical([-0.0f32, f32::from_bits(0xFFFF_FFFF)])).unwrap();
+///
+/// let bits: [u32; 2] = buffer.create().unwrap();
+/// assert_eq!(bits, [0, 0x7FC0_0000]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Canonical<T>(pub T);
+
+impl<T: ShaderType> ShaderType for Canonical<T> {
+    type ExtraMetadata = T::ExtraMetadata;
+    const METADATA: Metadata<Self::ExtraMetadata> = T::METADATA.no_pod();
+
+    const UNIFORM_COMPAT_CHECK: fn() -> Result<(), UniformCompatError> = T::UNIFORM_COMPAT_CHECK;
+
+    fn type_layout() -> TypeLayout {
+        T::type_layout()
+    }
+
+    fn size(&self) -> NonZeroU64 {
+        self.0.size()
+    }
+}
+
+impl<T: ShaderSize> ShaderSize for Canonical<T> {}
+
+impl<T: WriteInto> WriteInto for Canonical<T> {
+    #[inline]
+    fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+        let canonical_floats = writer.canonical_floats();
+        writer.set_canonical_floats(true);
+        WriteInto::write_into(&self.0, writer);
+        writer.set_canonical_floats(canonical_floats);
+    }
+}
+
+impl<T: ReadFrom> ReadFrom for Canonical<T> {
+    #[inline]
+    fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+        ReadFrom::read_from(&mut self.0, reader);
+    }
+}
+
+impl<T: CreateFrom> CreateFrom for Canonical<T> {
+    #[inline]
+    fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+        Canonical(CreateFrom::create_from(reader))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Canonical;
+    use crate::StorageBuffer;
+
+    fn write_bits<const N: usize>(value: &Canonical<[f32; N]>) -> [u32; N] {
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(value).unwrap();
+        buffer.create().unwrap()
+    }
+
+    #[test]
+    fn nan_and_negative_zero() {
+        let nans = [
+            f32::NAN,
+            -f32::NAN,
+            f32::from_bits(0x7F80_0001),
+            f32::from_bits(0xFFFF_FFFF),
+        ];
+        assert_eq!(write_bits(&Canonical(nans)), [0x7FC0_0000; 4]);
+        assert_eq!(write_bits(&Canonical([-0.0, 0.0])), [0, 0]);
+    }
+
+    #[test]
+    fn other_values_untouched() {
+        let values = [
+            1.5,
+            -2.0,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::MIN_POSITIVE,
+        ];
+        assert_eq!(write_bits(&Canonical(values)), values.map(f32::to_bits));
+
+        // only `f32`s are affected
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer
+            .write(&Canonical([0x8000_0000u32, 0xFFFF_FFFF]))
+            .unwrap();
+        let created: [u32; 2] = buffer.create().unwrap();
+        assert_eq!(created, [0x8000_0000, 0xFFFF_FFFF]);
+    }
+
+    #[test]
+    fn nested_and_restored() {
+        let mat = crate::NonSquareMat::<2, 3>([[-0.0, f32::NAN, 1.0], [2.0, -0.0, 3.0]]);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&Canonical(mat)).unwrap();
+        let columns: [[u32; 4]; 2] = buffer.create().unwrap();
+        assert_eq!(columns[0][..3], [0, 0x7FC0_0000, 1f32.to_bits()]);
+        assert_eq!(columns[1][..3], [2f32.to_bits(), 0, 3f32.to_bits()]);
+
+        // the writer goes back to writing floats as they are
+        let mut writer_buffer = Vec::<u8>::new();
+        let mut writer = crate::internal::Writer::with_size(8, &mut writer_buffer, 0).unwrap();
+        crate::internal::WriteInto::write_into(&Canonical(-0.0f32), &mut writer);
+        crate::internal::WriteInto::write_into(&-0.0f32, &mut writer);
+        writer.finish().unwrap();
+        assert_eq!(writer_buffer, [0, 0, 0, 0, 0, 0, 0, 0x80]);
+    }
+}
@@ -118,13 +118,21 @@ macro_rules! impl_matrix_inner {
     };
 
     (__main, $c:literal, $r:literal, $type:ty, $el_ty:ty, ($($generics:tt)*)) => {
-        const _: () = assert!(
+        const _: () = $crate::private::concat_assert!(
             2 <= $c && $c <= 4,
-            "Matrix should have at least 2 columns and at most 4!",
+            "matrix `",
+            ::core::stringify!($type),
+            "` should have at least 2 and at most 4 columns (got ",
+            $c,
+            " columns)"
         );
-        const _: () = assert!(
+        const _: () = $crate::private::concat_assert!(
             2 <= $r && $r <= 4,
-            "Matrix should have at least 2 rows and at most 4!",
+            "matrix `",
+            ::core::stringify!($type),
+            "` should have at least 2 and at most 4 rows (got ",
+            $r,
+            " rows)"
         );
 
         impl<$($generics)*> $crate::private::ShaderType for $type
@@ -148,6 +156,14 @@ macro_rules! impl_matrix_inner {
                     },
                 }
             };
+
+            fn type_layout() -> $crate::private::TypeLayout {
+                $crate::private::TypeLayout {
+                    alignment: Self::METADATA.alignment().get(),
+                    size: Self::METADATA.min_size().get(),
+                    kind: $crate::private::TypeLayoutKind::Matrix { columns: $c, rows: $r },
+                }
+            }
         }
 
         impl<$($generics)*> $crate::private::ShaderSize for $type
@@ -216,3 +232,67 @@ macro_rules! impl_matrix_inner {
         }
     };
 }
+
+/// Non-square `f32` matrix with `C` columns and `R` rows (equivalent to WGSL's `matCxR<f32>`)
+///
+/// Meant for math libraries that only provide square matrices (e.g. `glam`),
+/// supports all combinations of 2 to 4 columns and rows that aren't square
+///
+/// # Examples
+///
+/// ```
+/// # use encase::{NonSquareMat, ShaderType};
+/// // mat4x3<f32>, each column (a `vec3<f32>`) is padded to 16 bytes
+/// let mat = NonSquareMat::<4, 3>([[1.0; 3]; 4]);
+/// assert_eq!(mat.size().get(), 64);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NonSquareMat<const C: usize, const R: usize>(pub [[f32; R]; C]);
+
+impl<const C: usize, const R: usize> AsRef<[[f32; R]; C]> for NonSquareMat<C, R> {
+    fn as_ref(&self) -> &[[f32; R]; C] {
+        &self.0
+    }
+}
+
+impl<const C: usize, const R: usize> AsMut<[[f32; R]; C]> for NonSquareMat<C, R> {
+    fn as_mut(&mut self) -> &mut [[f32; R]; C] {
+        &mut self.0
+    }
+}
+
+impl<const C: usize, const R: usize> From<[[f32; R]; C]> for NonSquareMat<C, R> {
+    fn from(columns: [[f32; R]; C]) -> Self {
+        Self(columns)
+    }
+}
+
+impl_matrix!(2, 3, NonSquareMat<2, 3>, f32; using AsRef AsMut From);
+impl_matrix!(2, 4, NonSquareMat<2, 4>, f32; using AsRef AsMut From);
+impl_matrix!(3, 2, NonSquareMat<3, 2>, f32; using AsRef AsMut From);
+impl_matrix!(3, 4, NonSquareMat<3, 4>, f32; using AsRef AsMut From);
+impl_matrix!(4, 2, NonSquareMat<4, 2>, f32; using AsRef AsMut From);
+impl_matrix!(4, 3, NonSquareMat<4, 3>, f32; using AsRef AsMut From);
+
+/// Square matrix that is written transposed and transposed back when read
+///
+/// Useful for shaders that expect row-major matrices (e.g. ported from GLSL),
+/// the shader type stays the same (e.g. `Transposed<glam::Mat4>` is a `mat4x4<f32>`)
+/// but the bytes are those of `M`'s transpose
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "glam")] {
+/// # use encase::{StorageBuffer, Transposed};
+/// let mat = glam::Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+///
+/// let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+/// buffer.write(&Transposed(mat)).unwrap();
+///
+/// let written: glam::Mat2 = buffer.create().unwrap();
+/// assert_eq!(written, mat.transpose());
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Transposed<M>(pub M);
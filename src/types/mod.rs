@@ -11,4 +11,8 @@ pub mod r#struct;
 
 pub mod runtime_sized_array;
 
+pub mod packed_vec4;
+
+pub mod canonical;
+
 mod wrapper;
@@ -0,0 +1,99 @@
+use core::num::NonZeroU64;
+
+use crate::core::{
+    AlignmentValue, BufferMut, CalculateSizeFor, Metadata, RuntimeSizedArray, ShaderType,
+    SizeValue, UniformCompatError, WriteInto, Writer,
+};
+use crate::types::array::ArrayMetadata;
+use crate::types::runtime_sized_array::Length;
+
+/// Writes a slice of [`f32`]s as an `array<vec4<f32>>`
+///
+/// The values are grouped 4 at a time into `vec4<f32>` elements,
+/// the last element is zero-padded if the length of the slice is not a multiple of 4
+///
+/// This avoids having to manually pad flat data (an `array<f32>` in uniform buffers
+/// or an `array<vec3<f32>>` both require padding between elements)
+///
+/// Can be used together with [`ArrayLength`](crate::ArrayLength) which will contain the number of `vec4<f32>` elements
+///
+/// # Examples
+///
+/// ```
+/// # use encase::{PackedVec4, StorageBuffer};
+/// let data = [1.0, 2.0, 3.0, 4.0, 5.0];
+///
+/// let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+/// buffer.write(&PackedVec4(&data)).unwrap();
+///
+/// // 2 `vec4<f32>`s, the last 3 floats are zeros
+/// assert_eq!(buffer.as_ref().len(), 32);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PackedVec4<'a>(pub &'a [f32]);
+
+impl PackedVec4<'_> {
+    /// Returns the number of `vec4<f32>` elements the slice will be written as
+    pub const fn el_count(&self) -> usize {
+        (self.0.len() + 3) / 4
+    }
+}
+
+impl ShaderType for PackedVec4<'_> {
+    type ExtraMetadata = ArrayMetadata;
+    const METADATA: Metadata<Self::ExtraMetadata> = Metadata {
+        alignment: AlignmentValue::new(16),
+        has_uniform_min_alignment: true,
+        min_size: SizeValue::new(16),
+        is_pod: false,
+        extra: ArrayMetadata {
+            stride: SizeValue::new(16),
+            el_padding: 0,
+        },
+    };
+
+    const UNIFORM_COMPAT_CHECK: fn() -> Result<(), UniformCompatError> = || {
+        Err(UniformCompatError::RuntimeSizedArray {
+            ty: core::any::type_name::<Self>(),
+            field: None,
+        })
+    };
+
+    fn size(&self) -> NonZeroU64 {
+        Self::calculate_size_for(self.el_count() as u64)
+    }
+}
+
+impl Length for PackedVec4<'_> {
+    fn length(&self) -> usize {
+        self.el_count()
+    }
+}
+
+impl RuntimeSizedArray for PackedVec4<'_> {
+    fn len(&self) -> usize {
+        self.el_count()
+    }
+}
+
+impl CalculateSizeFor for PackedVec4<'_> {
+    fn calculate_size_for(nr_of_el: u64) -> NonZeroU64 {
+        Self::METADATA.stride().mul(nr_of_el.max(1)).0
+    }
+}
+
+impl WriteInto for PackedVec4<'_> {
+    fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+        for chunk in self.0.chunks(4) {
+            for value in chunk {
+                WriteInto::write_into(value, writer);
+            }
+            writer.write_zeros((4 - chunk.len()) * 4);
+        }
+
+        // an empty array still takes up the space of one element
+        if self.0.is_empty() {
+            writer.write_zeros(Self::METADATA.stride().get() as usize);
+        }
+    }
+}
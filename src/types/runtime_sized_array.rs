@@ -1,9 +1,11 @@
-use std::collections::{LinkedList, VecDeque};
+use core::num::NonZeroU64;
+use std::collections::{BTreeMap, BTreeSet, LinkedList, VecDeque};
 
 use crate::core::{
-    BufferMut, BufferRef, CreateFrom, Metadata, ReadFrom, Reader, RuntimeSizedArray, ShaderSize,
-    WriteInto, Writer,
+    BufferMut, BufferRef, CalculateSizeFor, CreateFrom, Metadata, ReadFrom, Reader,
+    RuntimeSizedArray, ShaderSize, UniformCompatError, WriteInto, Writer,
 };
+use crate::types::array::ArrayMetadata;
 use crate::ShaderType;
 
 /// Helper type meant to be used together with the [`derive@ShaderType`] derive macro
@@ -28,6 +30,9 @@ use crate::ShaderType;
 /// - on write, write the length of the contained runtime-sized array as an [`u32`] to the buffer
 ///
 /// - on read, read the value as an [`u32`] from the buffer (rep as `LEN`) and when reading the elements of the contained runtime-sized array a max of `LEN` elements will be read
+///
+/// Only one length can be in effect at a time: a struct contains at most one runtime-sized array (its last field)
+/// and structs containing one can't be used as fields of other structs or as array elements
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ArrayLength;
 
@@ -61,6 +66,11 @@ impl CreateFrom for ArrayLength {
 }
 
 pub trait Length {
+    /// Max number of elements the type can hold, if it has a fixed capacity
+    ///
+    /// Reads will stop after this many elements even if the buffer contains more
+    const CAPACITY: Option<usize> = None;
+
     fn length(&self) -> usize;
 }
 
@@ -103,6 +113,7 @@ macro_rules! impl_rts_array_inner {
     };
     (__inner, ($type:ty, $($generics:tt)*); ) => {
         $crate::impl_rts_array_inner!(__main, $type, $($generics)*);
+        $crate::impl_rts_array_inner!(__read, $type, $($generics)*);
     };
 
     (__len, $type:ty, $($generics:tt)*) => {
@@ -142,8 +153,23 @@ macro_rules! impl_rts_array_inner {
                 }
             };
 
-            const UNIFORM_COMPAT_ASSERT: fn() = ||
-                ::core::panic!("runtime-sized array can't be used in uniform buffers");
+            const UNIFORM_COMPAT_CHECK: fn() -> ::core::result::Result<(), $crate::private::UniformCompatError> = ||
+                ::core::result::Result::Err($crate::private::UniformCompatError::RuntimeSizedArray {
+                    ty: ::core::any::type_name::<Self>(),
+                    field: ::core::option::Option::None,
+                });
+
+            fn type_layout() -> $crate::private::TypeLayout {
+                $crate::private::TypeLayout {
+                    alignment: Self::METADATA.alignment().get(),
+                    size: Self::METADATA.min_size().get(),
+                    kind: $crate::private::TypeLayoutKind::Array {
+                        element: ::std::boxed::Box::new(T::type_layout()),
+                        stride: Self::METADATA.stride().get(),
+                        len: ::core::option::Option::None,
+                    },
+                }
+            }
 
             fn size(&self) -> ::core::num::NonZeroU64 {
                 use ::core::cmp::Ord;
@@ -177,7 +203,7 @@ macro_rules! impl_rts_array_inner {
         impl<$($generics)*> $crate::private::WriteInto for $type
         where
             T: $crate::private::WriteInto,
-            Self: $crate::private::ShaderType<ExtraMetadata = $crate::private::ArrayMetadata>,
+            Self: $crate::private::Length + $crate::private::ShaderType<ExtraMetadata = $crate::private::ArrayMetadata>,
             for<'a> &'a Self: ::core::iter::IntoIterator<Item = &'a T>,
         {
             fn write_into<B: $crate::private::BufferMut>(&self, writer: &mut $crate::private::Writer<B>) {
@@ -187,8 +213,15 @@ macro_rules! impl_rts_array_inner {
                     $crate::private::WriteInto::write_into(item, writer);
                     writer.advance(<Self as $crate::private::ShaderType>::METADATA.el_padding() as ::core::primitive::usize);
                 }
+
+                // an empty array still takes up the space of one element (see `ShaderType::size`)
+                if $crate::private::Length::length(self) == 0 {
+                    writer.write_zeros(<Self as $crate::private::ShaderType>::METADATA.stride().get() as ::core::primitive::usize);
+                }
             }
         }
+    };
+    (__read, $type:ty, $($generics:tt)*) => {
 
         impl<$($generics)*> $crate::private::ReadFrom for $type
         where
@@ -200,7 +233,11 @@ macro_rules! impl_rts_array_inner {
                 use ::core::cmp::Ord;
                 use ::core::iter::{IntoIterator, Extend, Iterator};
 
-                let max = reader.ctx.rts_array_max_el_to_read.unwrap_or(::core::primitive::u32::MAX) as ::core::primitive::usize;
+                let max = reader.ctx.rts_array_max_el_to_read.take().unwrap_or(::core::primitive::u32::MAX) as ::core::primitive::usize;
+                let max = match <Self as $crate::private::Length>::CAPACITY {
+                    ::core::option::Option::Some(capacity) => max.min(capacity),
+                    ::core::option::Option::None => max,
+                };
                 let count = max.min(reader.remaining() / <Self as $crate::private::ShaderType>::METADATA.stride().get() as ::core::primitive::usize);
                 $crate::private::Truncate::truncate(self, count);
 
@@ -224,13 +261,17 @@ macro_rules! impl_rts_array_inner {
         impl<$($generics)*> $crate::private::CreateFrom for $type
         where
             T: $crate::private::CreateFrom,
-            Self: ::core::iter::FromIterator<T> + $crate::private::ShaderType<ExtraMetadata = $crate::private::ArrayMetadata>,
+            Self: $crate::private::Length + ::core::iter::FromIterator<T> + $crate::private::ShaderType<ExtraMetadata = $crate::private::ArrayMetadata>,
         {
             fn create_from<B: $crate::private::BufferRef>(reader: &mut $crate::private::Reader<B>) -> Self {
                 use ::core::cmp::Ord;
                 use ::core::iter::Iterator;
 
-                let max = reader.ctx.rts_array_max_el_to_read.unwrap_or(::core::primitive::u32::MAX) as ::core::primitive::usize;
+                let max = reader.ctx.rts_array_max_el_to_read.take().unwrap_or(::core::primitive::u32::MAX) as ::core::primitive::usize;
+                let max = match <Self as $crate::private::Length>::CAPACITY {
+                    ::core::option::Option::Some(capacity) => max.min(capacity),
+                    ::core::option::Option::None => max,
+                };
                 let count = max.min(reader.remaining() / <Self as $crate::private::ShaderType>::METADATA.stride().get() as ::core::primitive::usize);
 
                 ::core::iter::FromIterator::from_iter(
@@ -246,7 +287,10 @@ macro_rules! impl_rts_array_inner {
     };
 }
 
-impl_rts_array!([T]; using len);
+// slices can't grow or shrink, they get a `ReadFrom` impl that reads in place (see below)
+impl_rts_array_inner!(__len, [T], T);
+impl_rts_array_inner!(__main, [T], T);
+
 impl_rts_array!(Vec<T>; using len truncate);
 impl_rts_array!(VecDeque<T>; using len truncate);
 impl_rts_array!(LinkedList<T>; using len);
@@ -259,6 +303,130 @@ impl<T> Truncate for LinkedList<T> {
     }
 }
 
+// sets are laid out in order, they can be created but not read into (there is no way to update elements in place)
+impl_rts_array!(BTreeSet<T>; using len);
+
+// only the values are laid out (in key order), keys are not part of the shader representation
+
+impl<K, V: ShaderType + ShaderSize> ShaderType for BTreeMap<K, V> {
+    type ExtraMetadata = ArrayMetadata;
+    const METADATA: Metadata<Self::ExtraMetadata> = <[V]>::METADATA;
+
+    const UNIFORM_COMPAT_CHECK: fn() -> Result<(), UniformCompatError> =
+        <[V]>::UNIFORM_COMPAT_CHECK;
+
+    fn size(&self) -> NonZeroU64 {
+        Self::calculate_size_for(self.len() as u64)
+    }
+}
+
+impl<K, V> Length for BTreeMap<K, V> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V: ShaderType + ShaderSize> RuntimeSizedArray for BTreeMap<K, V> {
+    fn len(&self) -> usize {
+        Length::length(self)
+    }
+}
+
+impl<K, V: ShaderType + ShaderSize> CalculateSizeFor for BTreeMap<K, V> {
+    fn calculate_size_for(nr_of_el: u64) -> NonZeroU64 {
+        <[V]>::calculate_size_for(nr_of_el)
+    }
+}
+
+impl<K, V: WriteInto + ShaderType + ShaderSize> WriteInto for BTreeMap<K, V> {
+    fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+        for value in self.values() {
+            WriteInto::write_into(value, writer);
+            writer.advance(Self::METADATA.el_padding() as usize);
+        }
+
+        if self.is_empty() {
+            writer.write_zeros(Self::METADATA.stride().get() as usize);
+        }
+    }
+}
+
+/// Reads the values in place (in key order), up to `self.len()` of them
+impl<K, V: ReadFrom + ShaderType + ShaderSize> ReadFrom for BTreeMap<K, V> {
+    fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+        let max = reader
+            .ctx
+            .rts_array_max_el_to_read
+            .take()
+            .unwrap_or(u32::MAX) as usize;
+        let count = max.min(reader.remaining() / Self::METADATA.stride().get() as usize);
+
+        for value in self.values_mut().take(count) {
+            ReadFrom::read_from(value, reader);
+            reader.advance(Self::METADATA.el_padding() as usize);
+        }
+    }
+}
+
+/// Reads up to `self.len()` elements in place
+impl<T> ReadFrom for [T]
+where
+    T: ReadFrom,
+    Self: ShaderType<ExtraMetadata = ArrayMetadata>,
+{
+    fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+        let max = reader
+            .ctx
+            .rts_array_max_el_to_read
+            .take()
+            .unwrap_or(u32::MAX) as usize;
+        let count = max.min(reader.remaining() / Self::METADATA.stride().get() as usize);
+
+        for item in self.iter_mut().take(count) {
+            ReadFrom::read_from(item, reader);
+            reader.advance(Self::METADATA.el_padding() as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod btree {
+    use crate::{ShaderType, StorageBuffer};
+    use std::collections::{BTreeMap, BTreeSet};
+
+    #[test]
+    fn btree_set() {
+        let set: BTreeSet<u32> = [5, 1, 4, 2].into_iter().collect();
+        assert_eq!(set.size().get(), 16);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&set).unwrap();
+        assert_eq!(
+            buffer.as_ref(),
+            &[1, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 5, 0, 0, 0]
+        );
+
+        let created: BTreeSet<u32> = buffer.create().unwrap();
+        assert_eq!(created, set);
+    }
+
+    #[test]
+    fn btree_map_values() {
+        let map: BTreeMap<&str, u32> = [("c", 3), ("a", 1), ("b", 2)].into_iter().collect();
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&map).unwrap();
+
+        let values: Vec<u32> = buffer.create().unwrap();
+        assert_eq!(values, [1, 2, 3]);
+
+        let mut map: BTreeMap<&str, u32> = [("y", 0), ("x", 0)].into_iter().collect();
+        buffer.read(&mut map).unwrap();
+        assert_eq!(map["x"], 1);
+        assert_eq!(map["y"], 2);
+    }
+}
+
 #[cfg(test)]
 mod array_length {
     use super::ArrayLength;
@@ -269,4 +437,26 @@ mod array_length {
 
         assert_eq!(format!("{ArrayLength:?}"), "ArrayLength");
     }
+
+    #[test]
+    fn length_is_consumed() {
+        use crate::core::{CreateFrom, Reader};
+
+        let bytes: Vec<u8> = [2u32, 1, 2, 3, 4]
+            .iter()
+            .flat_map(|n| n.to_le_bytes())
+            .collect();
+        let mut reader = Reader::new::<[u32]>(&bytes[..], 0).unwrap();
+
+        let _: ArrayLength = CreateFrom::create_from(&mut reader);
+        assert_eq!(reader.ctx.rts_array_max_el_to_read, Some(2));
+
+        let first: Vec<u32> = CreateFrom::create_from(&mut reader);
+        assert_eq!(first, [1, 2]);
+        assert_eq!(reader.ctx.rts_array_max_el_to_read, None);
+
+        // the length doesn't leak to arrays read after the one it belongs to
+        let rest: Vec<u32> = CreateFrom::create_from(&mut reader);
+        assert_eq!(rest, [3, 4]);
+    }
 }
@@ -1,9 +1,10 @@
 use crate::core::{
     BufferMut, BufferRef, CreateFrom, Metadata, ReadFrom, Reader, ShaderSize, ShaderType,
-    WriteInto, Writer,
+    TypeLayout, TypeLayoutKind, WriteInto, Writer,
 };
 use core::num::{NonZeroI32, NonZeroU32, Wrapping};
 use core::sync::atomic::{AtomicI32, AtomicU32};
+use std::net::{Ipv4Addr, Ipv6Addr};
 
 macro_rules! impl_basic_traits {
     ($type:ty) => {
@@ -16,6 +17,14 @@ macro_rules! impl_basic_traits {
         impl ShaderType for $type {
             type ExtraMetadata = ();
             const METADATA: Metadata<Self::ExtraMetadata> = Metadata::from_alignment_and_size(4, 4) $($tail)*;
+
+            fn type_layout() -> TypeLayout {
+                TypeLayout {
+                    alignment: 4,
+                    size: 4,
+                    kind: TypeLayoutKind::Scalar,
+                }
+            }
         }
 
         impl ShaderSize for $type {}
@@ -24,12 +33,20 @@ macro_rules! impl_basic_traits {
 
 macro_rules! impl_traits_for_pod {
     ($type:ty) => {
+        impl_traits_for_pod!($type, core::convert::identity);
+    };
+    ($type:ty, $canonicalize:path) => {
         impl_basic_traits!($type, is_pod);
 
         impl WriteInto for $type {
             #[inline]
             fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
-                writer.write(&<$type>::to_le_bytes(*self));
+                let value = if writer.canonical_floats() {
+                    $canonicalize(*self)
+                } else {
+                    *self
+                };
+                writer.write(&<$type>::to_le_bytes(value));
             }
         }
 
@@ -49,10 +66,20 @@ macro_rules! impl_traits_for_pod {
     };
 }
 
-impl_traits_for_pod!(f32);
+impl_traits_for_pod!(f32, canonicalize_f32);
 impl_traits_for_pod!(u32);
 impl_traits_for_pod!(i32);
 
+fn canonicalize_f32(value: f32) -> f32 {
+    if value.is_nan() {
+        f32::from_bits(0x7FC0_0000)
+    } else if value == 0.0 {
+        0.0
+    } else {
+        value
+    }
+}
+
 macro_rules! impl_traits_for_non_zero_option {
     ($type:ty) => {
         impl_basic_traits!(Option<$type>);
@@ -145,6 +172,157 @@ macro_rules! impl_traits_for_atomic {
 impl_traits_for_atomic!(AtomicU32);
 impl_traits_for_atomic!(AtomicI32);
 
+// `char`s are laid out as their code point (a `u32`).
+// Reading a `u32` that is not a valid Unicode scalar value results in `char::REPLACEMENT_CHARACTER`
+// (reading can't fail after the buffer size was checked).
+
+impl_basic_traits!(char);
+
+impl WriteInto for char {
+    #[inline]
+    fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+        WriteInto::write_into(&u32::from(*self), writer);
+    }
+}
+
+impl ReadFrom for char {
+    #[inline]
+    fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+        *self = CreateFrom::create_from(reader);
+    }
+}
+
+impl CreateFrom for char {
+    #[inline]
+    fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+        char::from_u32(CreateFrom::create_from(reader)).unwrap_or(char::REPLACEMENT_CHARACTER)
+    }
+}
+
+// WGSL has no 128-bit scalars, `u128`s and `i128`s are laid out like a `vec4<u32>`
+// (shaders have to treat them as 4 `u32`s, the first one holding the least significant bits)
+
+macro_rules! impl_traits_for_128_bit_int {
+    ($type:ty) => {
+        impl ShaderType for $type {
+            type ExtraMetadata = ();
+            const METADATA: Metadata<Self::ExtraMetadata> =
+                Metadata::from_alignment_and_size(16, 16);
+
+            fn type_layout() -> TypeLayout {
+                TypeLayout {
+                    alignment: 16,
+                    size: 16,
+                    kind: TypeLayoutKind::Vector { len: 4 },
+                }
+            }
+        }
+
+        impl ShaderSize for $type {}
+
+        impl WriteInto for $type {
+            #[inline]
+            fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+                writer.write(&<$type>::to_le_bytes(*self));
+            }
+        }
+
+        impl ReadFrom for $type {
+            #[inline]
+            fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+                *self = <$type>::from_le_bytes(*reader.read());
+            }
+        }
+
+        impl CreateFrom for $type {
+            #[inline]
+            fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+                <$type>::from_le_bytes(*reader.read())
+            }
+        }
+    };
+}
+
+impl_traits_for_128_bit_int!(u128);
+impl_traits_for_128_bit_int!(i128);
+
+// IP addresses are laid out as the numeric value of their octets in address order (most significant first),
+// `Ipv4Addr`s like a `u32` (`u32::from(addr)`, e.g. `192.168.0.1` is `0xC0A80001`)
+// and `Ipv6Addr`s like a `vec4<u32>` holding 4 octets each (the first one holding the first 4 octets).
+// This lets shaders apply masks and compare prefixes with integer operations,
+// note that the bytes in the buffer are little-endian (not in network byte order).
+
+impl_basic_traits!(Ipv4Addr);
+
+impl WriteInto for Ipv4Addr {
+    #[inline]
+    fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+        WriteInto::write_into(&u32::from(*self), writer);
+    }
+}
+
+impl ReadFrom for Ipv4Addr {
+    #[inline]
+    fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+        *self = CreateFrom::create_from(reader);
+    }
+}
+
+impl CreateFrom for Ipv4Addr {
+    #[inline]
+    fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+        Ipv4Addr::from(<u32 as CreateFrom>::create_from(reader))
+    }
+}
+
+impl ShaderType for Ipv6Addr {
+    type ExtraMetadata = ();
+    const METADATA: Metadata<Self::ExtraMetadata> = Metadata::from_alignment_and_size(16, 16);
+
+    fn type_layout() -> TypeLayout {
+        TypeLayout {
+            alignment: 16,
+            size: 16,
+            kind: TypeLayoutKind::Vector { len: 4 },
+        }
+    }
+}
+
+impl ShaderSize for Ipv6Addr {}
+
+impl WriteInto for Ipv6Addr {
+    #[inline]
+    fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+        let bits = u128::from(*self);
+        let lanes = [3, 2, 1, 0].map(|i| (bits >> (32 * i)) as u32);
+        WriteInto::write_into(&lanes, writer);
+    }
+}
+
+impl ReadFrom for Ipv6Addr {
+    #[inline]
+    fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+        *self = CreateFrom::create_from(reader);
+    }
+}
+
+impl CreateFrom for Ipv6Addr {
+    #[inline]
+    fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+        let lanes: [u32; 4] = CreateFrom::create_from(reader);
+        let bits = lanes
+            .iter()
+            .fold(0u128, |bits, lane| (bits << 32) | u128::from(*lane));
+        Ipv6Addr::from(bits)
+    }
+}
+
+// 64-bit integers are not host-shareable in WGSL, these impls only exist to turn
+// the unsatisfied trait bound error users would get into one explaining what to do instead.
+
+impl_traits_for_unsupported_64_bit_int!(u64, "`[u32; 2]`");
+impl_traits_for_unsupported_64_bit_int!(i64, "`[i32; 2]`");
+
 macro_rules! impl_marker_trait_for_f32 {
     ($trait:path) => {
         impl $trait for ::core::primitive::f32 {}
@@ -127,6 +127,14 @@ macro_rules! impl_vector_inner {
                     extra: ()
                 }
             };
+
+            fn type_layout() -> $crate::private::TypeLayout {
+                $crate::private::TypeLayout {
+                    alignment: Self::METADATA.alignment().get(),
+                    size: Self::METADATA.min_size().get(),
+                    kind: $crate::private::TypeLayoutKind::Vector { len: $n },
+                }
+            }
         }
 
         impl<$($generics)*> $crate::private::ShaderSize for $type
@@ -171,3 +179,53 @@ macro_rules! impl_vector_inner {
         }
     };
 }
+
+/// Vector that is laid out as an array of its components (`array<T, N>`)
+///
+/// Drops the alignment of the vector down to the one of its components, useful for tightly packed data
+/// that the shader reads as a flat array (e.g. `Packed<glam::Vec3>` is 12 bytes and 4 byte aligned
+/// while `glam::Vec3` is 12 bytes and 16 byte aligned)
+///
+/// Note that arrays in the uniform address space need a stride of at least 16
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "glam")] {
+/// # use encase::{Packed, ShaderType, StorageBuffer};
+/// let positions = [
+///     Packed(glam::Vec3::new(1.0, 2.0, 3.0)),
+///     Packed(glam::Vec3::new(4.0, 5.0, 6.0)),
+/// ];
+///
+/// let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+/// buffer.write(&positions).unwrap();
+///
+/// // 6 `f32`s, no padding in between
+/// assert_eq!(buffer.as_ref().len(), 24);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Packed<V>(pub V);
+
+/// Float vector that is laid out as a vector of the bits of its components (`vecN<u32>`)
+///
+/// The bits are written as they are (no conversion happens), useful for packing arbitrary data
+/// into a float vector that the shader reads as `vecN<u32>` (and reinterprets with `bitcast`)
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "glam")] {
+/// # use encase::{Bitcast, StorageBuffer};
+/// let value = glam::Vec4::new(1.0, f32::from_bits(7), -0.0, f32::NAN);
+///
+/// let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+/// buffer.write(&Bitcast(value)).unwrap();
+///
+/// let lanes: [u32; 4] = buffer.create().unwrap();
+/// assert_eq!(lanes, value.to_array().map(f32::to_bits));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Bitcast<V>(pub V);
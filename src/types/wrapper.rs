@@ -1,3 +1,5 @@
+use crate::core::{BufferRef, CreateFrom, Reader};
+
 /// Used to implement `ShaderType` for the given wrapper type
 ///
 /// # Args
@@ -43,7 +45,12 @@ macro_rules! impl_wrapper_inner {
             type ExtraMetadata = T::ExtraMetadata;
             const METADATA: $crate::private::Metadata<Self::ExtraMetadata> = T::METADATA.no_pod();
 
-            const UNIFORM_COMPAT_ASSERT: fn() = T::UNIFORM_COMPAT_ASSERT;
+            const UNIFORM_COMPAT_CHECK: fn() -> ::core::result::Result<(), $crate::private::UniformCompatError> = T::UNIFORM_COMPAT_CHECK;
+
+            #[inline]
+            fn type_layout() -> $crate::private::TypeLayout {
+                T::type_layout()
+            }
 
             #[inline]
             fn size(&self) -> ::core::num::NonZeroU64 {
@@ -75,6 +82,11 @@ macro_rules! impl_wrapper_inner {
             fn calculate_size_for(nr_of_el: u64) -> ::core::num::NonZeroU64 {
                 <T as $crate::private::CalculateSizeFor>::calculate_size_for(nr_of_el)
             }
+
+            #[inline]
+            fn header_size() -> u64 {
+                <T as $crate::private::CalculateSizeFor>::header_size()
+            }
         }
 
         impl<$($generics)*> $crate::private::WriteInto for $type
@@ -114,7 +126,31 @@ macro_rules! impl_wrapper_inner {
 impl_wrapper!(&T; using Ref{});
 impl_wrapper!(&mut T; using Ref{} Mut{});
 impl_wrapper!(Box<T>; using Ref{} Mut{} From{ new });
-impl_wrapper!(std::borrow::Cow<'_, T>; (T: ?Sized + ToOwned<Owned = T>); using Ref{} From{ Owned });
+impl_wrapper!(std::borrow::Cow<'_, T>; (T: ?Sized + ToOwned); using Ref{});
+impl_wrapper!(std::borrow::Cow<'_, T>; (T: ToOwned<Owned = T>); using From{ Owned });
 impl_wrapper!(std::rc::Rc<T>; using Ref{} From{ new });
 impl_wrapper!(std::sync::Arc<T>; using Ref{} From{ new });
 impl_wrapper!(core::cell::Cell<T>; (T: Copy); using Ref{ .get() } Mut{ .get_mut() } From{ new });
+
+// boxed slices can't grow, create them from a `Vec` instead
+macro_rules! impl_create_from_for_slice_wrappers {
+    ($($type:ty),*) => {$(
+        impl<T> CreateFrom for $type
+        where
+            Vec<T>: CreateFrom,
+            Self: From<Vec<T>>,
+        {
+            #[inline]
+            fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+                Self::from(<Vec<T> as CreateFrom>::create_from(reader))
+            }
+        }
+    )*};
+}
+
+impl_create_from_for_slice_wrappers!(
+    Box<[T]>,
+    std::rc::Rc<[T]>,
+    std::sync::Arc<[T]>,
+    std::borrow::Cow<'_, [T]>
+);
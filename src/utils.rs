@@ -1,8 +1,5 @@
 use core::mem::MaybeUninit;
 
-#[track_caller]
-pub const fn consume_zsts<const N: usize>(_: [(); N]) {}
-
 #[doc(hidden)]
 #[macro_export]
 macro_rules! build_struct {
@@ -43,6 +40,57 @@ macro_rules! if_pod_and_little_endian {
             $false
         }
     }};
+    // floats have to go through their `WriteInto` impl to be canonicalized
+    (writer: $writer:expr; if pod_and_little_endian $true:block else $false:block) => {{
+        #[cfg(target_endian = "little")]
+        if <Self as $crate::private::ShaderType>::METADATA.is_pod() && !$writer.canonical_floats() {
+            $true
+        } else {
+            $false
+        }
+        #[cfg(not(target_endian = "little"))]
+        {
+            $false
+        }
+    }};
+}
+
+// Implements the traits for types containing 64-bit integers (not host-shareable in WGSL) with metadata that panics
+// with a message naming the type and suggesting a 32-bit replacement (`$suggestion`).
+// The error is raised when the metadata is evaluated (e.g. when the type is written, read or its size is taken),
+// the bodies of the read/write methods are unreachable since they can't be called without evaluating it first.
+macro_rules! impl_traits_for_unsupported_64_bit_int {
+    ($type:ty, $suggestion:literal) => {
+        impl $crate::core::ShaderType for $type {
+            type ExtraMetadata = ();
+            const METADATA: $crate::core::Metadata<Self::ExtraMetadata> = panic!(concat!(
+                stringify!($type),
+                " is not a WGSL host-shareable type; split it into 32-bit parts (e.g. ",
+                $suggestion,
+                "), 64-bit integers are only available through the `shader-i64` extension (wgpu's `SHADER_INT64`) which is not supported"
+            ));
+        }
+
+        impl $crate::core::ShaderSize for $type {}
+
+        impl $crate::core::WriteInto for $type {
+            fn write_into<B: $crate::core::BufferMut>(&self, _: &mut $crate::core::Writer<B>) {
+                unreachable!()
+            }
+        }
+
+        impl $crate::core::ReadFrom for $type {
+            fn read_from<B: $crate::core::BufferRef>(&mut self, _: &mut $crate::core::Reader<B>) {
+                unreachable!()
+            }
+        }
+
+        impl $crate::core::CreateFrom for $type {
+            fn create_from<B: $crate::core::BufferRef>(_: &mut $crate::core::Reader<B>) -> Self {
+                unreachable!()
+            }
+        }
+    };
 }
 
 #[cfg(any(feature = "glam", feature = "ultraviolet", feature = "vek"))]
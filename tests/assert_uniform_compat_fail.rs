@@ -48,7 +48,7 @@ fn test_struct_first() {
 }
 
 #[test]
-#[should_panic]
+#[should_panic = "stride of array `[u32; 8]` in field 'a' must be a multiple of 16 (current stride: 4)"]
 fn test_array_stride() {
     #[derive(ShaderType)]
     struct TestArrayStride {
@@ -59,7 +59,7 @@ fn test_array_stride() {
 }
 
 #[test]
-#[should_panic]
+#[should_panic = "<f32>` in field 'a' can't be used in uniform buffers"]
 fn test_rts_array() {
     #[derive(ShaderType)]
     struct TestRTSArray {
@@ -69,3 +69,20 @@ fn test_rts_array() {
 
     TestRTSArray::assert_uniform_compat();
 }
+
+#[test]
+#[should_panic = "stride of array `[f32; 4]` in field 'values' must be a multiple of 16 (current stride: 4)"]
+fn test_nested_array_stride() {
+    #[derive(ShaderType)]
+    struct Inner {
+        values: [f32; 4],
+    }
+
+    #[derive(ShaderType)]
+    struct TestNested {
+        a: mint::Vector4<f32>,
+        inner: Inner,
+    }
+
+    TestNested::assert_uniform_compat();
+}
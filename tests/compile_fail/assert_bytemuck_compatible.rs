@@ -0,0 +1,5 @@
+use encase::ShaderType;
+
+fn main() {
+    <[mint::Vector3<f32>; 4]>::assert_bytemuck_compatible();
+}
@@ -0,0 +1,12 @@
+use encase::{assert_shader_size, ShaderType};
+
+fn main() {}
+
+#[derive(ShaderType)]
+struct Test {
+    a: u32,
+    b: u32,
+}
+
+assert_shader_size!(Test, 8);
+assert_shader_size!(Test, 16);
@@ -0,0 +1,12 @@
+use encase::ShaderType;
+
+fn main() {}
+
+#[derive(ShaderType)]
+enum Test {
+    A(#[align(16)] u32),
+    B {
+        #[size(16)]
+        b: f32,
+    },
+}
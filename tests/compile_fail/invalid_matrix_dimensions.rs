@@ -0,0 +1,7 @@
+use encase::impl_matrix;
+
+fn main() {}
+
+struct Mat5x2([[f32; 2]; 5]);
+
+impl_matrix!(5, 2, Mat5x2, f32);
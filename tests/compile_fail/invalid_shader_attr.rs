@@ -0,0 +1,13 @@
+use encase::ShaderType;
+
+fn main() {}
+
+#[derive(ShaderType)]
+struct Test {
+    #[shader(aligh(16))]
+    a: u32,
+    #[shader(align(16), sise(8))]
+    b: u32,
+    #[shader(align(3))]
+    c: u32,
+}
@@ -0,0 +1,9 @@
+use encase::ShaderType;
+
+fn main() {}
+
+#[derive(ShaderType)]
+struct Test {
+    a: Vec<u32>,
+    b: u32,
+}
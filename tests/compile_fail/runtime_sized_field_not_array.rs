@@ -0,0 +1,10 @@
+use encase::ShaderType;
+
+fn main() {}
+
+#[derive(ShaderType)]
+struct Test {
+    a: u32,
+    #[size(runtime)]
+    b: u32,
+}
@@ -0,0 +1,16 @@
+use encase::ShaderType;
+
+fn main() {}
+
+#[derive(ShaderType)]
+struct Inner {
+    a: u32,
+    #[size(runtime)]
+    b: Vec<u32>,
+}
+
+#[derive(ShaderType)]
+struct Outer {
+    #[size(runtime)]
+    items: Vec<Inner>,
+}
@@ -0,0 +1,19 @@
+use encase::ShaderType;
+
+fn main() {}
+
+#[derive(ShaderType)]
+struct Test {
+    a: u32,
+    #[size(runtime)]
+    #[size(16)]
+    b: Vec<u32>,
+}
+
+#[derive(ShaderType)]
+struct Test2 {
+    a: u32,
+    #[size(8)]
+    #[size(16)]
+    b: u32,
+}
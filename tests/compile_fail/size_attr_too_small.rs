@@ -0,0 +1,10 @@
+use encase::ShaderType;
+
+fn main() {}
+
+#[derive(ShaderType)]
+struct Test {
+    #[size(2)]
+    a: u32,
+    b: u32,
+}
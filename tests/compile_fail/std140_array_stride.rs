@@ -0,0 +1,19 @@
+use encase::ShaderType;
+
+fn main() {
+    let _ = Generic::<4>::METADATA;
+}
+
+#[derive(ShaderType)]
+#[shader(layout = "std140")]
+struct Test {
+    a: u32,
+    b: [f32; 4],
+}
+
+#[derive(ShaderType)]
+#[shader(layout = "std140")]
+struct Generic<const N: usize> {
+    a: u32,
+    b: [f32; N],
+}
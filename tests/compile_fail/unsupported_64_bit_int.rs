@@ -0,0 +1,11 @@
+use encase::{ShaderType, StorageBuffer};
+
+#[derive(ShaderType)]
+struct Test {
+    a: u64,
+}
+
+fn main() {
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&Test { a: 1 }).unwrap();
+}
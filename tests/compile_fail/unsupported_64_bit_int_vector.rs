@@ -0,0 +1,11 @@
+use encase::{ShaderType, StorageBuffer};
+
+#[derive(ShaderType)]
+struct Test {
+    a: glam::U64Vec3,
+}
+
+fn main() {
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&Test { a: glam::U64Vec3::ONE }).unwrap();
+}
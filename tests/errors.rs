@@ -1,4 +1,7 @@
-use encase::{internal::Error, ShaderType, StorageBuffer};
+use encase::{
+    internal::{Error, UniformCompatError},
+    DynamicUniformBuffer, ShaderType, StorageBuffer, UniformBuffer,
+};
 
 #[test]
 fn buffer_too_small() {
@@ -34,3 +37,94 @@ fn buffer_too_small() {
         })
     ));
 }
+
+#[test]
+fn fixed_size_buffer_one_byte_too_small() {
+    #[derive(ShaderType)]
+    struct Test {
+        a: mint::Vector4<f32>,
+        b: u32,
+    }
+
+    let v = Test {
+        a: mint::Vector4::from([1.0, 2.0, 3.0, 4.0]),
+        b: 5,
+    };
+    assert_eq!(v.size().get(), 32);
+
+    let mut buffer = StorageBuffer::new([0u8; 31]);
+    assert!(matches!(
+        buffer.write(&v),
+        Err(Error::BufferTooSmall {
+            expected: 32,
+            found: 31
+        })
+    ));
+    assert_eq!(buffer.into_inner(), [0u8; 31]);
+
+    let mut buffer = StorageBuffer::new([core::mem::MaybeUninit::<u8>::uninit(); 31]);
+    assert!(matches!(
+        buffer.write(&v),
+        Err(Error::BufferTooSmall {
+            expected: 32,
+            found: 31
+        })
+    ));
+}
+
+#[test]
+fn uniform_incompatible() {
+    #[derive(ShaderType)]
+    struct S {
+        x: f32,
+    }
+
+    #[derive(ShaderType)]
+    struct Test {
+        a: u32,
+        b: S,
+    }
+
+    let expected = UniformCompatError::FieldOffsetDiff {
+        prev_field: "a",
+        field: "b",
+        min_alignment: 16,
+        diff: 4,
+    };
+    assert_eq!(Test::check_uniform_compat(), Err(expected));
+
+    let mut v = Test {
+        a: 4,
+        b: S { x: 1.0 },
+    };
+    let mut buffer = UniformBuffer::new(Vec::<u8>::new());
+
+    assert!(matches!(
+        buffer.try_write(&v),
+        Err(Error::UniformIncompatible(err)) if err == expected
+    ));
+
+    assert!(matches!(
+        buffer.try_read(&mut v),
+        Err(Error::UniformIncompatible(err)) if err == expected
+    ));
+
+    assert!(matches!(
+        buffer.try_create::<Test>(),
+        Err(Error::UniformIncompatible(err)) if err == expected
+    ));
+
+    let mut buffer = DynamicUniformBuffer::new(Vec::<u8>::new());
+
+    assert!(matches!(
+        buffer.try_write(&[1u32; 2]),
+        Err(Error::UniformIncompatible(
+            UniformCompatError::ArrayStride {
+                ty: "[u32; 2]",
+                field: None,
+                min_alignment: 16,
+                stride: 4
+            }
+        ))
+    ));
+}
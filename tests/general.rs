@@ -114,6 +114,30 @@ fn size() {
     assert_eq!(a.size().get(), 4080);
 }
 
+#[test]
+fn header_size() {
+    #[derive(ShaderType)]
+    struct Header {
+        count: u32,
+        #[size(runtime)]
+        values: Vec<u32>,
+    }
+
+    #[derive(ShaderType)]
+    struct AlignedHeader {
+        count: u32,
+        #[size(runtime)]
+        values: Vec<mint::Vector4<f32>>,
+    }
+
+    assert_eq!(Header::header_size(), 4);
+    assert_eq!(Header::calculate_size_for(0).get(), 8);
+    assert_eq!(AlignedHeader::header_size(), 16);
+    assert_eq!(AlignedHeader::calculate_size_for(3).get(), 16 + 3 * 16);
+    assert_eq!(<Vec<u32>>::header_size(), 0);
+    assert_eq!(<&Header>::header_size(), 4);
+}
+
 #[test]
 fn calculate_size_for() {
     assert_eq!(<&A>::calculate_size_for(12).get(), 2832);
@@ -158,3 +182,937 @@ fn test_opt_writing() {
     in_buffer2.write(&data2).unwrap();
     assert_eq!(in_byte_buffer, in_byte_buffer2);
 }
+
+#[test]
+fn empty_rts_array() {
+    #[derive(ShaderType)]
+    struct Test {
+        a: u32,
+        #[size(runtime)]
+        arr: Vec<u32>,
+    }
+
+    let t = Test {
+        a: 7,
+        arr: Vec::new(),
+    };
+    assert_eq!(t.size().get(), 8);
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&t).unwrap();
+    assert_eq!(buffer.as_ref().len() as u64, t.size().get());
+
+    let mut buffer = StorageBuffer::new(vec![0xFF_u8; 8]);
+    buffer.write(&t).unwrap();
+    assert_eq!(buffer.as_ref(), &[7, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn slice_wrappers_round_trip() {
+    #[derive(ShaderType, Debug, PartialEq)]
+    struct Particle {
+        position: mint::Vector3<f32>,
+        mass: f32,
+    }
+
+    let particles = (0..4)
+        .map(|i| Particle {
+            position: mint::Vector3::from([i as f32, 1.0, 2.0]),
+            mass: 0.5 * i as f32,
+        })
+        .collect::<Vec<_>>()
+        .into_boxed_slice();
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&particles).unwrap();
+
+    let boxed: Box<[Particle]> = buffer.create().unwrap();
+    assert_eq!(boxed, particles);
+
+    let rc: std::rc::Rc<[Particle]> = buffer.create().unwrap();
+    assert_eq!(&*rc, &*particles);
+
+    let arc: std::sync::Arc<[Particle]> = buffer.create().unwrap();
+    assert_eq!(&*arc, &*particles);
+}
+
+#[test]
+fn cow_slices_round_trip() {
+    use std::borrow::Cow;
+
+    #[derive(ShaderType, Clone, Debug, PartialEq)]
+    struct Particle {
+        position: mint::Vector3<f32>,
+        mass: f32,
+    }
+
+    let particles = (0..4)
+        .map(|i| Particle {
+            position: mint::Vector3::from([i as f32, 1.0, 2.0]),
+            mass: 0.5 * i as f32,
+        })
+        .collect::<Vec<_>>();
+
+    let mut expected = StorageBuffer::new(Vec::<u8>::new());
+    expected.write(particles.as_slice()).unwrap();
+
+    let borrowed: Cow<'_, [Particle]> = Cow::Borrowed(&particles);
+    let owned: Cow<'_, [Particle]> = Cow::Owned(particles.clone());
+
+    for cow in [borrowed, owned] {
+        assert_eq!(cow.size().get(), expected.as_ref().len() as u64);
+
+        let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&cow).unwrap();
+        assert_eq!(buffer.as_ref(), expected.as_ref());
+
+        let created: Cow<'_, [Particle]> = buffer.create().unwrap();
+        assert!(matches!(created, Cow::Owned(_)));
+        assert_eq!(created, cow);
+    }
+}
+
+#[test]
+fn write_chunked() {
+    use rand::SeedableRng;
+    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
+    let a = gen_a(&mut rng);
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&a).unwrap();
+    let expected = buffer.into_inner();
+
+    for chunk_size in [1, 3, 16, 100, 256, 4080, 5000] {
+        let mut chunks = Vec::new();
+        encase::write_chunked(&a, chunk_size, |chunk| chunks.push(chunk.to_vec())).unwrap();
+
+        assert!(chunks[..chunks.len() - 1]
+            .iter()
+            .all(|chunk| chunk.len() == chunk_size));
+        assert_eq!(chunks.concat(), expected);
+    }
+
+    // only as much memory as the value needs is allocated
+    let mut chunks = Vec::new();
+    encase::write_chunked(&1u32, usize::MAX, |chunk| chunks.push(chunk.to_vec())).unwrap();
+    assert_eq!(chunks, [[1, 0, 0, 0]]);
+}
+
+#[test]
+fn char_round_trip() {
+    let chars = ['a', 'é', '字', '🦀'];
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&chars).unwrap();
+
+    let code_points: [u32; 4] = buffer.create().unwrap();
+    assert_eq!(code_points, [0x61, 0xE9, 0x5B57, 0x1F980]);
+
+    let created: [char; 4] = buffer.create().unwrap();
+    assert_eq!(created, chars);
+
+    let mut read = ['\0'; 4];
+    buffer.read(&mut read).unwrap();
+    assert_eq!(read, chars);
+}
+
+#[test]
+fn char_invalid_code_point() {
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&[0xD800_u32, 0x110000]).unwrap();
+
+    let created: [char; 2] = buffer.create().unwrap();
+    assert_eq!(created, [char::REPLACEMENT_CHARACTER; 2]);
+}
+
+#[test]
+fn skipped_fields() {
+    #[derive(ShaderType, Debug, PartialEq)]
+    struct Test<T> {
+        #[shader(skip)]
+        label: &'static str,
+        a: u32,
+        #[size(runtime)]
+        data: Vec<u32>,
+        #[shader(skip)]
+        _m: core::marker::PhantomData<T>,
+    }
+
+    let t = Test::<f32> {
+        label: "test",
+        a: 1,
+        data: vec![2, 3],
+        _m: core::marker::PhantomData,
+    };
+    assert_eq!(t.size().get(), 12);
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&t).unwrap();
+    assert_eq!(buffer.as_ref(), &[1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0]);
+
+    let created: Test<f32> = buffer.create().unwrap();
+    assert_eq!(created, Test { label: "", ..t });
+}
+
+#[test]
+fn shared_slices() {
+    #[derive(ShaderType)]
+    struct Particle {
+        position: mint::Vector3<f32>,
+        mass: f32,
+    }
+
+    #[derive(ShaderType)]
+    struct Particles {
+        count: u32,
+        #[size(runtime)]
+        particles: std::sync::Arc<[Particle]>,
+    }
+
+    let particles = (0..4)
+        .map(|i| Particle {
+            position: mint::Vector3::from([i as f32, 1.0, 2.0]),
+            mass: 0.5 * i as f32,
+        })
+        .collect::<Vec<_>>();
+
+    let mut expected = StorageBuffer::new(Vec::<u8>::new());
+    expected.write(particles.as_slice()).unwrap();
+
+    let wrapped = Particles {
+        count: particles.len() as u32,
+        particles: particles.into(),
+    };
+    assert_eq!(wrapped.size().get(), 16 + expected.as_ref().len() as u64);
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&wrapped).unwrap();
+    assert_eq!(&buffer.as_ref()[16..], expected.as_ref());
+}
+
+#[test]
+fn u128_round_trip() {
+    let values = [0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210_u128, u128::MAX, 1];
+    assert_eq!(<[u128; 3]>::METADATA.alignment().get(), 16);
+    assert_eq!(<[u128; 3]>::min_size().get(), 48);
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&values).unwrap();
+
+    let parts: [[u32; 4]; 3] = buffer.create().unwrap();
+    assert_eq!(
+        parts[0],
+        [0x7654_3210, 0xFEDC_BA98, 0x89AB_CDEF, 0x0123_4567]
+    );
+
+    let created: [u128; 3] = buffer.create().unwrap();
+    assert_eq!(created, values);
+
+    let value = i128::MIN + 1;
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&value).unwrap();
+    let created: i128 = buffer.create().unwrap();
+    assert_eq!(created, value);
+}
+
+#[test]
+fn ip_addr_round_trip() {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    let v4 = [Ipv4Addr::new(192, 168, 0, 1), Ipv4Addr::BROADCAST];
+    assert_eq!(Ipv4Addr::min_size().get(), 4);
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&v4).unwrap();
+    assert_eq!(&buffer.as_ref()[..4], &[1, 0, 168, 192]);
+
+    let raw: [u32; 2] = buffer.create().unwrap();
+    assert_eq!(raw, [0xC0A8_0001, u32::MAX]);
+
+    let created: [Ipv4Addr; 2] = buffer.create().unwrap();
+    assert_eq!(created, v4);
+
+    let v6 = Ipv6Addr::new(0x2001, 0x0db8, 0x85a3, 0, 0, 0x8a2e, 0x0370, 0x7334);
+    assert_eq!(Ipv6Addr::METADATA.alignment().get(), 16);
+    assert_eq!(Ipv6Addr::min_size().get(), 16);
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&v6).unwrap();
+
+    let raw: [u32; 4] = buffer.create().unwrap();
+    assert_eq!(raw, [0x2001_0db8, 0x85a3_0000, 0x0000_8a2e, 0x0370_7334]);
+
+    let created: Ipv6Addr = buffer.create().unwrap();
+    assert_eq!(created, v6);
+
+    let mut read = Ipv6Addr::UNSPECIFIED;
+    buffer.read(&mut read).unwrap();
+    assert_eq!(read, v6);
+}
+
+#[test]
+fn packed_vec4() {
+    use encase::PackedVec4;
+
+    let data: Vec<f32> = (1..=8).map(|i| i as f32).collect();
+
+    let packed = PackedVec4(&data);
+    assert_eq!(packed.el_count(), 2);
+    assert_eq!(packed.size().get(), 32);
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&packed).unwrap();
+    let created: Vec<[f32; 4]> = buffer.create().unwrap();
+    assert_eq!(created, [[1., 2., 3., 4.], [5., 6., 7., 8.]]);
+
+    let packed = PackedVec4(&data[..5]);
+    assert_eq!(packed.el_count(), 2);
+    assert_eq!(packed.size().get(), 32);
+
+    let mut buffer = StorageBuffer::new(vec![0xFF_u8; 32]);
+    buffer.write(&packed).unwrap();
+    let created: Vec<[f32; 4]> = buffer.create().unwrap();
+    assert_eq!(created, [[1., 2., 3., 4.], [5., 0., 0., 0.]]);
+
+    #[derive(ShaderType)]
+    struct Data<'a> {
+        length: ArrayLength,
+        #[size(runtime)]
+        values: PackedVec4<'a>,
+    }
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer
+        .write(&Data {
+            length: ArrayLength,
+            values: PackedVec4(&data[..6]),
+        })
+        .unwrap();
+    let length: u32 = buffer.create().unwrap();
+    assert_eq!(length, 2);
+    assert_eq!(buffer.as_ref().len(), 48);
+}
+
+#[test]
+fn array_length_placement() {
+    #[derive(ShaderType)]
+    struct Inner {
+        x: u32,
+        y: mint::Vector2<f32>,
+    }
+
+    #[derive(ShaderType)]
+    struct Test {
+        a: u32,
+        length: ArrayLength,
+        b: Inner,
+        c: mint::Vector3<f32>,
+        d: f32,
+        #[size(runtime)]
+        values: Vec<u32>,
+    }
+
+    let test = Test {
+        a: 1,
+        length: ArrayLength,
+        b: Inner {
+            x: 2,
+            y: mint::Vector2::from([3.0, 4.0]),
+        },
+        c: mint::Vector3::from([5.0, 6.0, 7.0]),
+        d: 8.0,
+        values: vec![9, 10, 11],
+    };
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&test).unwrap();
+    let header: [u32; 2] = buffer.create().unwrap();
+    assert_eq!(header, [1, 3]);
+
+    let created: Test = buffer.create().unwrap();
+    assert_eq!(created.values, [9, 10, 11]);
+}
+
+#[test]
+fn write_iter() {
+    let positions = [[1.0_f32, 2.0], [3.0, 4.0], [5.0, 6.0]];
+    let to_vec3 = |p: &[f32; 2]| mint::Vector3::from([p[0], p[1], 0.0]);
+
+    let mut expected = StorageBuffer::new(Vec::<u8>::new());
+    expected
+        .write(&positions.iter().map(to_vec3).collect::<Vec<_>>())
+        .unwrap();
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write_iter(positions.iter().map(to_vec3)).unwrap();
+    assert_eq!(buffer.as_ref(), expected.as_ref());
+
+    let mut buffer = StorageBuffer::new(vec![0xFF_u8; 16]);
+    buffer
+        .write_iter(core::iter::empty::<mint::Vector3<f32>>())
+        .unwrap();
+    assert_eq!(buffer.as_ref(), &[0; 16]);
+}
+
+#[test]
+fn read_into_slice() {
+    let values = [1u32, 2, 3, 4];
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&values).unwrap();
+
+    let mut stack = [0u32; 6];
+    buffer.read(&mut stack[..]).unwrap();
+    assert_eq!(stack, [1, 2, 3, 4, 0, 0]);
+
+    let mut heap = vec![0u32; 3].into_boxed_slice();
+    buffer.read(&mut *heap).unwrap();
+    assert_eq!(*heap, [1, 2, 3]);
+}
+
+#[test]
+fn non_square_mat() {
+    use encase::NonSquareMat;
+
+    type Mat4x3 = NonSquareMat<4, 3>;
+    assert_eq!(Mat4x3::METADATA.alignment().get(), 16);
+    assert_eq!(Mat4x3::min_size().get(), 64);
+
+    let mat = Mat4x3::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.], [10., 11., 12.]]);
+
+    let mut buffer = StorageBuffer::new(vec![0xFF_u8; 64]);
+    buffer.write(&mat).unwrap();
+
+    let columns: [[f32; 4]; 4] = buffer.create().unwrap();
+    for (col, expected) in columns.iter().zip(&mat.0) {
+        assert_eq!(col[..3], expected[..]);
+    }
+    // column padding is skipped over when writing
+    assert!(buffer.as_ref()[12..16].iter().all(|b| *b == 0xFF));
+
+    let created: Mat4x3 = buffer.create().unwrap();
+    assert_eq!(created, mat);
+
+    let mat = NonSquareMat::<3, 2>([[1., 2.], [3., 4.], [5., 6.]]);
+    assert_eq!(mat.size().get(), 24);
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&mat).unwrap();
+    let created: [[f32; 2]; 3] = buffer.create().unwrap();
+    assert_eq!(created, mat.0);
+}
+
+#[test]
+fn vec_deque_wraparound() {
+    use std::collections::VecDeque;
+
+    let mut deque = VecDeque::with_capacity(4);
+    deque.extend([0u32, 1, 2, 3]);
+    // forces the ring buffer to wrap around
+    deque.pop_front();
+    deque.pop_front();
+    deque.push_back(4);
+    deque.push_back(5);
+
+    let (front, back) = deque.as_slices();
+    assert!(!front.is_empty() && !back.is_empty());
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&deque).unwrap();
+
+    let values: Vec<u32> = buffer.create().unwrap();
+    assert_eq!(values, [2, 3, 4, 5]);
+
+    let created: VecDeque<u32> = buffer.create().unwrap();
+    assert_eq!(created, deque);
+
+    let mut read = VecDeque::with_capacity(4);
+    read.extend([9u32, 9, 9]);
+    read.pop_front();
+    read.push_back(9);
+    buffer.read(&mut read).unwrap();
+    assert_eq!(read, deque);
+}
+
+#[test]
+fn pod_struct_array_round_trip() {
+    #[derive(ShaderType, Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    struct Pod {
+        a: mint::Vector4<f32>,
+        b: [u32; 4],
+    }
+
+    let pods = [
+        Pod {
+            a: mint::Vector4::from([1.0, 2.0, 3.0, 4.0]),
+            b: [5, 6, 7, 8],
+        },
+        Pod {
+            a: mint::Vector4::from([9.0, 10.0, 11.0, 12.0]),
+            b: [13, 14, 15, 16],
+        },
+    ];
+    assert!(<[Pod; 2]>::IS_POD);
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&pods).unwrap();
+
+    let flat: [u32; 16] = buffer.create().unwrap();
+    assert_eq!(flat[4..8], [5, 6, 7, 8]);
+    assert_eq!(flat[12..], [13, 14, 15, 16]);
+
+    let created: [Pod; 2] = buffer.create().unwrap();
+    assert_eq!(created, pods);
+}
+
+#[test]
+fn generate_repr_c() {
+    use encase::ShaderSize;
+
+    #[derive(ShaderType)]
+    #[shader(generate_repr_c)]
+    struct Foo {
+        a: u32,
+        b: mint::Vector3<f32>,
+        c: f32,
+        d: mint::Vector2<f32>,
+    }
+
+    assert_eq!(
+        core::mem::size_of::<FooGpu>() as u64,
+        Foo::SHADER_SIZE.get()
+    );
+
+    let foo = Foo {
+        a: 1,
+        b: mint::Vector3::from([2.0, 3.0, 4.0]),
+        c: 5.0,
+        d: mint::Vector2::from([6.0, 7.0]),
+    };
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&foo).unwrap();
+
+    let gpu = FooGpu::from(foo);
+    assert_eq!(gpu._pad0, [0; 12]);
+    assert_eq!(gpu._pad3, [0; 8]);
+
+    // SAFETY: `FooGpu` is `#[repr(C)]` and has no implicit padding
+    let gpu_bytes = unsafe {
+        core::slice::from_raw_parts(
+            &gpu as *const FooGpu as *const u8,
+            core::mem::size_of::<FooGpu>(),
+        )
+    };
+    assert_eq!(buffer.as_ref().as_slice(), gpu_bytes);
+}
+
+#[test]
+fn hand_written_impls() {
+    use encase::internal::{
+        BufferMut, BufferRef, CreateFrom, Metadata, ReadFrom, Reader, WriteInto, Writer,
+    };
+    use encase::ShaderSize;
+
+    // a `vec4<u32>` in the shader, only the lowest 16 bits of each component are used
+    #[derive(Debug, PartialEq)]
+    struct Packed([u16; 4]);
+
+    impl ShaderType for Packed {
+        type ExtraMetadata = ();
+        const METADATA: Metadata<()> = Metadata::from_alignment_and_size(16, 16);
+    }
+
+    impl ShaderSize for Packed {}
+
+    impl WriteInto for Packed {
+        fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+            for val in self.0 {
+                writer.write(&val.to_le_bytes());
+                writer.advance(2);
+            }
+        }
+    }
+
+    impl ReadFrom for Packed {
+        fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+            *self = CreateFrom::create_from(reader);
+        }
+    }
+
+    impl CreateFrom for Packed {
+        fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+            Packed(core::array::from_fn(|_| {
+                let val = u16::from_le_bytes(*reader.read());
+                reader.advance(2);
+                val
+            }))
+        }
+    }
+
+    #[derive(ShaderType, Debug, PartialEq)]
+    struct Test {
+        a: u32,
+        b: Packed,
+    }
+
+    let test = Test {
+        a: 1,
+        b: Packed([2, 3, 4, 5]),
+    };
+
+    let mut buffer = StorageBuffer::new(vec![0xFF_u8; 32]);
+    buffer.write(&test).unwrap();
+
+    let raw: [u32; 8] = buffer.create().unwrap();
+    assert_eq!(
+        raw[4..],
+        [0xFFFF_0002, 0xFFFF_0003, 0xFFFF_0004, 0xFFFF_0005]
+    );
+
+    let created: Test = buffer.create().unwrap();
+    assert_eq!(created, test);
+}
+
+#[test]
+fn shared_vecs() {
+    use std::{rc::Rc, sync::Arc};
+
+    #[derive(ShaderType, Debug, PartialEq)]
+    struct Particle {
+        position: mint::Vector3<f32>,
+        mass: f32,
+    }
+
+    #[derive(ShaderType)]
+    struct Particles {
+        length: ArrayLength,
+        #[size(runtime)]
+        particles: Arc<Vec<Particle>>,
+    }
+
+    let particles = (0..3)
+        .map(|i| Particle {
+            position: mint::Vector3::from([i as f32, 1.0, 2.0]),
+            mass: 0.5 * i as f32,
+        })
+        .collect::<Vec<_>>();
+
+    let mut expected = StorageBuffer::new(Vec::<u8>::new());
+    expected.write(&particles).unwrap();
+
+    let arc = Arc::new(particles);
+    assert_eq!(arc.size().get(), 48);
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&arc).unwrap();
+    assert_eq!(buffer.as_ref(), expected.as_ref());
+
+    let created: Arc<Vec<Particle>> = buffer.create().unwrap();
+    assert_eq!(created, arc);
+
+    let rc = Rc::new(arc.iter().collect::<Vec<_>>());
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&rc).unwrap();
+    assert_eq!(buffer.as_ref(), expected.as_ref());
+
+    let wrapped = Particles {
+        length: ArrayLength,
+        particles: arc.clone(),
+    };
+    assert_eq!(
+        <Particles as CalculateSizeFor>::calculate_size_for(3).get(),
+        64
+    );
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&wrapped).unwrap();
+    assert_eq!(&buffer.as_ref()[..4], &[3, 0, 0, 0]);
+    assert_eq!(&buffer.as_ref()[16..], expected.as_ref());
+}
+
+#[test]
+fn tagged_union() {
+    #[derive(Debug, PartialEq, ShaderType)]
+    enum Shape {
+        Empty,
+        Circle(f32),
+        Quad { corners: mint::Vector4<f32> },
+    }
+
+    assert_eq!(Shape::METADATA.alignment().get(), 16);
+    assert_eq!(Shape::min_size().get(), 32);
+
+    let layout = Shape::type_layout();
+    assert_eq!((layout.alignment, layout.size), (16, 32));
+    match layout.kind {
+        encase::TypeLayoutKind::Struct { fields } => {
+            let fields: Vec<_> = fields
+                .iter()
+                .map(|field| (field.name, field.offset, field.padding, field.layout.size))
+                .collect();
+            assert_eq!(fields, [("tag", 0, 12, 4), ("payload", 16, 0, 16)]);
+        }
+        kind => panic!("unexpected layout kind {kind:?}"),
+    }
+
+    let shapes = [
+        Shape::Empty,
+        Shape::Circle(2.0),
+        Shape::Quad {
+            corners: mint::Vector4::from([1.0, 2.0, 3.0, 4.0]),
+        },
+    ];
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&shapes).unwrap();
+    let bytes = buffer.as_ref();
+    assert_eq!(bytes.len(), 96);
+
+    assert_eq!(&bytes[0..4], &0u32.to_ne_bytes());
+    assert_eq!(&bytes[32..36], &1u32.to_ne_bytes());
+    assert_eq!(&bytes[48..52], &2.0f32.to_ne_bytes());
+    assert_eq!(&bytes[64..68], &2u32.to_ne_bytes());
+    assert_eq!(&bytes[80..84], &1.0f32.to_ne_bytes());
+    assert_eq!(&bytes[92..96], &4.0f32.to_ne_bytes());
+
+    let created: [Shape; 3] = buffer.create().unwrap();
+    assert_eq!(created, shapes);
+
+    let mut read = [Shape::Empty, Shape::Empty, Shape::Circle(0.0)];
+    buffer.read(&mut read).unwrap();
+    assert_eq!(read, shapes);
+}
+
+#[test]
+fn tagged_union_discriminants() {
+    #[derive(Debug, PartialEq, ShaderType)]
+    enum Mode {
+        A = 1,
+        B = 4,
+        C,
+    }
+
+    let modes = [Mode::A, Mode::B, Mode::C];
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&modes).unwrap();
+
+    let tags: [u32; 3] = buffer.create().unwrap();
+    assert_eq!(tags, [1, 4, 5]);
+
+    let created: [Mode; 3] = buffer.create().unwrap();
+    assert_eq!(created, modes);
+}
+
+#[test]
+#[should_panic(expected = "invalid tag 3 for enum `Flag`")]
+fn tagged_union_invalid_tag() {
+    #[derive(Debug, ShaderType)]
+    enum Flag {
+        Off,
+        On(u32),
+    }
+
+    let buffer = StorageBuffer::new([3, 0, 0, 0, 0, 0, 0, 0]);
+    let _: Flag = buffer.create().unwrap();
+}
+
+#[test]
+fn soa_write2() {
+    #[derive(ShaderType)]
+    struct Particle {
+        position: mint::Vector3<f32>,
+        color: mint::Vector4<f32>,
+    }
+
+    let positions: Vec<[f32; 3]> = (0..5).map(|i| [i as f32, 1.0, 2.0]).collect();
+    let colors: Vec<[f32; 4]> = (0..5).map(|i| [0.5, i as f32, 0.0, 1.0]).collect();
+
+    let particles: Vec<Particle> = positions
+        .iter()
+        .zip(&colors)
+        .map(|(position, color)| Particle {
+            position: (*position).into(),
+            color: (*color).into(),
+        })
+        .collect();
+
+    let mut expected = StorageBuffer::new(Vec::<u8>::new());
+    expected.write(&particles).unwrap();
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    encase::soa::write2(&positions, &colors, &mut buffer, |position, color| {
+        Particle {
+            position: (*position).into(),
+            color: (*color).into(),
+        }
+    })
+    .unwrap();
+
+    assert_eq!(buffer.as_ref().len(), 160);
+    assert_eq!(buffer.as_ref(), expected.as_ref());
+}
+
+#[test]
+#[should_panic(expected = "the slices must have the same length (2 != 1)")]
+fn soa_write2_length_mismatch() {
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    let _ = encase::soa::write2(&[1u32, 2], &[3u32], &mut buffer, |a, b| a + b);
+}
+
+#[test]
+fn read_array_length() {
+    #[derive(ShaderType)]
+    struct Points {
+        id: u32,
+        count: ArrayLength,
+        bounds: mint::Vector4<f32>,
+        #[size(runtime)]
+        points: Vec<mint::Vector4<f32>>,
+    }
+
+    let value = Points {
+        id: 7,
+        count: ArrayLength,
+        bounds: mint::Vector4::from([0.0, 0.0, 1.0, 1.0]),
+        points: vec![mint::Vector4::from([0.5; 4]); 1000],
+    };
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&value).unwrap();
+
+    let offset = Points::METADATA.offset(1);
+    assert_eq!(offset, 4);
+    assert_eq!(buffer.read_array_length(offset).unwrap(), 1000);
+
+    let encase::TypeLayoutKind::Struct { fields } = Points::type_layout().kind else {
+        unreachable!()
+    };
+    let field = fields.iter().find(|field| field.name == "count").unwrap();
+    assert_eq!(buffer.read_array_length(field.offset).unwrap(), 1000);
+}
+
+#[test]
+fn to_vec_from_bytes() {
+    #[derive(Debug, ShaderType, PartialEq)]
+    struct Test {
+        a: u32,
+        b: mint::Vector3<f32>,
+        #[size(runtime)]
+        c: Vec<i32>,
+    }
+
+    let value = Test {
+        a: 7,
+        b: mint::Vector3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        },
+        c: vec![-1, -2, -3],
+    };
+
+    let bytes = encase::to_vec(&value).unwrap();
+    assert_eq!(bytes.len() as u64, value.size().get());
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&value).unwrap();
+    assert_eq!(bytes, buffer.into_inner());
+
+    let created: Test = encase::from_bytes(&bytes).unwrap();
+    assert_eq!(created, value);
+
+    let created: u32 = encase::from_bytes(&bytes[..4]).unwrap();
+    assert_eq!(created, 7);
+}
+
+#[test]
+fn create_bounded() {
+    #[derive(Debug, ShaderType)]
+    struct Untrusted {
+        count: ArrayLength,
+        #[size(runtime)]
+        items: Vec<mint::Vector4<u32>>,
+    }
+
+    // the length claims `u32::MAX` elements and the buffer is big enough for 4095 of them
+    let mut bytes = vec![1u8; 1 << 16];
+    bytes[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+    let buffer = StorageBuffer::new(bytes);
+
+    let created: Untrusted = buffer.create().unwrap();
+    assert_eq!(created.items.len(), 4095);
+
+    let created: Untrusted = buffer.create_bounded(256).unwrap();
+    assert_eq!(created.items.len(), 15);
+    assert_eq!(created.items[14], mint::Vector4::from([0x01010101; 4]));
+
+    let created: Untrusted = buffer.create_bounded(1 << 20).unwrap();
+    assert_eq!(created.items.len(), 4095);
+
+    assert!(matches!(
+        buffer.create_bounded::<Untrusted>(16),
+        Err(encase::internal::Error::BufferTooSmall {
+            expected: 32,
+            found: 16
+        })
+    ));
+}
+
+#[test]
+fn single_runtime_sized_field() {
+    #[derive(Debug, ShaderType, PartialEq)]
+    struct Buf {
+        #[size(runtime)]
+        data: Vec<u32>,
+    }
+
+    let value = Buf {
+        data: vec![1, 2, 3],
+    };
+
+    assert_eq!(Buf::METADATA.offset(0), 0);
+    assert_eq!(Buf::METADATA.padding(0), 0);
+    assert_eq!(Buf::header_size(), 0);
+    assert_eq!(Buf::min_size().get(), 4);
+    assert_eq!(value.size(), value.data.size());
+    assert_eq!(
+        Buf::calculate_size_for(3),
+        <Vec<u32>>::calculate_size_for(3)
+    );
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&value).unwrap();
+    assert_eq!(buffer.as_ref(), &[1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0]);
+
+    let created: Buf = buffer.create().unwrap();
+    assert_eq!(created, value);
+
+    let mut read = Buf { data: vec![0; 2] };
+    buffer.read(&mut read).unwrap();
+    assert_eq!(read, value);
+
+    let empty = Buf { data: Vec::new() };
+    assert_eq!(empty.size().get(), 4);
+
+    // the struct's size is rounded up to its alignment, the array's isn't
+    #[derive(Debug, ShaderType, PartialEq)]
+    struct AlignedBuf {
+        #[align(16)]
+        #[size(runtime)]
+        data: Vec<u32>,
+    }
+
+    let value = AlignedBuf {
+        data: vec![1, 2, 3],
+    };
+
+    assert_eq!(AlignedBuf::METADATA.alignment().get(), 16);
+    assert_eq!(value.size().get(), 16);
+    assert_eq!(AlignedBuf::calculate_size_for(3).get(), 16);
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&value).unwrap();
+    assert_eq!(buffer.as_ref().len(), 16);
+
+    // without an `ArrayLength` field the trailing padding is read as an element
+    let created: AlignedBuf = buffer.create().unwrap();
+    assert_eq!(created.data, [1, 2, 3, 0]);
+}
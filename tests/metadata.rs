@@ -1,4 +1,4 @@
-use encase::ShaderType;
+use encase::{ArrayStride, ShaderSize, ShaderType};
 
 #[derive(ShaderType)]
 struct WrappedF32 {
@@ -10,3 +10,267 @@ struct WrappedF32 {
 fn field_padding() {
     assert_eq!(WrappedF32::METADATA.padding(0), 12);
 }
+
+#[test]
+fn std140_layout() {
+    #[derive(ShaderType)]
+    struct DefaultLayout {
+        a: f32,
+        b: mint::Vector2<f32>,
+    }
+
+    #[derive(ShaderType)]
+    #[shader(layout = "std140")]
+    struct Std140Layout {
+        a: f32,
+        b: mint::Vector2<f32>,
+    }
+
+    assert_eq!(DefaultLayout::METADATA.alignment().get(), 8);
+    assert_eq!(Std140Layout::METADATA.alignment().get(), 16);
+    assert_eq!(DefaultLayout::METADATA.offset(1), 8);
+    assert_eq!(Std140Layout::METADATA.offset(1), 8);
+    assert_eq!(DefaultLayout::min_size().get(), 16);
+    assert_eq!(Std140Layout::min_size().get(), 16);
+
+    // the vec2 keeps its offset, the difference shows up when the structs are nested
+    #[derive(ShaderType)]
+    struct OuterDefault {
+        a: f32,
+        b: DefaultLayout,
+    }
+
+    #[derive(ShaderType)]
+    struct OuterStd140 {
+        a: f32,
+        b: Std140Layout,
+    }
+
+    assert_eq!(OuterDefault::METADATA.offset(1), 8);
+    assert_eq!(OuterStd140::METADATA.offset(1), 16);
+    assert_eq!(OuterDefault::min_size().get(), 24);
+    assert_eq!(OuterStd140::min_size().get(), 32);
+
+    #[derive(ShaderType)]
+    struct Inner {
+        x: f32,
+    }
+
+    #[derive(ShaderType)]
+    struct NestedDefaultLayout {
+        a: f32,
+        b: Inner,
+        c: f32,
+    }
+
+    #[derive(ShaderType)]
+    #[shader(layout = "std140")]
+    struct NestedStd140Layout {
+        a: f32,
+        b: Inner,
+        c: f32,
+    }
+
+    assert_eq!(NestedDefaultLayout::METADATA.offset(1), 4);
+    assert_eq!(NestedDefaultLayout::METADATA.offset(2), 8);
+    assert_eq!(NestedDefaultLayout::min_size().get(), 12);
+    assert!(NestedDefaultLayout::check_uniform_compat().is_err());
+
+    assert_eq!(NestedStd140Layout::METADATA.offset(1), 16);
+    assert_eq!(NestedStd140Layout::METADATA.offset(2), 32);
+    assert_eq!(NestedStd140Layout::METADATA.padding(0), 12);
+    assert_eq!(NestedStd140Layout::METADATA.padding(1), 12);
+    assert_eq!(NestedStd140Layout::min_size().get(), 48);
+    assert!(NestedStd140Layout::check_uniform_compat().is_ok());
+}
+
+#[test]
+fn array_stride() {
+    type Vec3u = mint::Vector3<u32>;
+
+    assert_eq!(Vec3u::array_stride(), 16);
+    assert_eq!(Vec3u::array_stride(), <[Vec3u; 2]>::SHADER_SIZE.get() / 2);
+    assert_eq!(WrappedF32::array_stride(), 16);
+    assert_eq!(f32::array_stride(), 4);
+    assert_eq!(<[f32; 3]>::array_stride(), 12);
+}
+
+#[test]
+fn is_pod() {
+    #[derive(ShaderType)]
+    #[repr(C)]
+    struct Pod {
+        a: mint::Vector4<f32>,
+        b: [u32; 4],
+    }
+
+    #[derive(ShaderType)]
+    struct NotReprC {
+        a: mint::Vector4<f32>,
+        b: [u32; 4],
+    }
+
+    #[derive(ShaderType)]
+    #[repr(C)]
+    struct Padded {
+        a: u32,
+        b: mint::Vector4<f32>,
+    }
+
+    #[derive(ShaderType)]
+    #[repr(C)]
+    struct TrailingPadding {
+        a: mint::Vector4<f32>,
+        b: u32,
+    }
+
+    #[derive(ShaderType)]
+    #[repr(C)]
+    struct WithRtArray {
+        a: u32,
+        #[size(runtime)]
+        b: Vec<u32>,
+    }
+
+    assert!(Pod::IS_POD);
+    assert!(<[Pod; 2]>::IS_POD);
+    assert!(!NotReprC::IS_POD);
+    assert!(!Padded::IS_POD);
+    assert!(!TrailingPadding::IS_POD);
+    assert!(!WithRtArray::IS_POD);
+}
+
+#[test]
+fn min_size_const() {
+    #[derive(ShaderType)]
+    struct Test {
+        a: u32,
+        #[size(runtime)]
+        b: Vec<mint::Vector4<f32>>,
+    }
+
+    struct Staging<T>(T);
+
+    impl<T: ShaderType> Staging<T> {
+        const MIN_SIZE: u64 = T::MIN_SIZE.get();
+    }
+
+    const SIZE: usize = <Test as ShaderType>::MIN_SIZE.get() as usize;
+    let staging = [0u8; SIZE];
+
+    assert_eq!(staging.len(), 32);
+    assert_eq!(Staging::<Test>::MIN_SIZE, 32);
+    assert_eq!(Staging::<[u32; 3]>::MIN_SIZE, 12);
+    assert_eq!(Test::MIN_SIZE, Test::min_size());
+}
+
+#[test]
+fn large_alignment() {
+    #[derive(ShaderType)]
+    struct Scratch {
+        header: u32,
+        #[align(4096)]
+        page: [u32; 4],
+        footer: u32,
+    }
+
+    assert_eq!(Scratch::METADATA.alignment().get(), 4096);
+    assert_eq!(Scratch::METADATA.offset(1), 4096);
+    assert_eq!(Scratch::METADATA.offset(2), 4112);
+    assert_eq!(Scratch::METADATA.padding(0), 4092);
+    assert_eq!(Scratch::SHADER_SIZE.get(), 8192);
+
+    let value = Scratch {
+        header: 1,
+        page: [2; 4],
+        footer: 3,
+    };
+
+    let mut buffer = encase::StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&value).unwrap();
+    assert_eq!(buffer.as_ref().len(), 8192);
+    assert_eq!(&buffer.as_ref()[4096..4100], &[2, 0, 0, 0]);
+    assert_eq!(&buffer.as_ref()[4112..4116], &[3, 0, 0, 0]);
+
+    let created: Scratch = buffer.create().unwrap();
+    assert_eq!(created.page, [2; 4]);
+    assert_eq!(created.footer, 3);
+}
+
+#[test]
+fn verify_layout() {
+    use encase::{verify_layout, LayoutMismatch};
+
+    #[derive(ShaderType)]
+    #[repr(C)]
+    struct Pod {
+        a: mint::Vector4<f32>,
+        b: [u32; 4],
+    }
+
+    // same size as the shader struct but `b` is right after `a` in memory
+    #[derive(ShaderType)]
+    #[repr(C, align(16))]
+    struct Mixed {
+        a: u32,
+        b: mint::Vector4<f32>,
+    }
+
+    assert_eq!(verify_layout::<Pod>(), Ok(()));
+    assert_eq!(verify_layout::<[Pod; 3]>(), Ok(()));
+
+    assert_eq!(
+        verify_layout::<[mint::Vector3<f32>; 4]>(),
+        Err(LayoutMismatch::Size {
+            rust: 48,
+            shader: 64
+        })
+    );
+    assert_eq!(
+        verify_layout::<mint::Vector3<f32>>(),
+        Err(LayoutMismatch::ArrayStride {
+            rust: 12,
+            shader: 16
+        })
+    );
+    assert_eq!(
+        verify_layout::<Mixed>(),
+        Err(LayoutMismatch::FieldPadding {
+            field: "a",
+            padding: 12
+        })
+    );
+}
+
+#[test]
+fn offsets_increase() {
+    // the derive asserts at compile time that no field overlaps the next one
+    #[derive(ShaderType)]
+    #[shader(layout = "std140")]
+    struct Mixed {
+        a: f32,
+        b: mint::Vector3<f32>,
+        c: f32,
+        #[size(20)]
+        d: mint::Vector2<f32>,
+        #[align(32)]
+        e: [mint::Vector4<u32>; 3],
+        f: WrappedF32,
+    }
+
+    let offsets = [0, 1, 2, 3, 4, 5].map(|i| Mixed::METADATA.offset(i));
+    assert_eq!(offsets, [0, 16, 28, 32, 64, 112]);
+    assert!(offsets.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn array_stride_consts() {
+    assert_eq!(<[mint::Vector3<u32>; 4]>::STRIDE, 16);
+    assert_eq!(<[mint::Vector3<u32>; 4]>::EL_PADDING, 4);
+
+    assert_eq!(<[u32; 4]>::STRIDE, 4);
+    assert_eq!(<[u32; 4]>::EL_PADDING, 0);
+
+    assert_eq!(<Vec<mint::Vector3<f32>>>::STRIDE, 16);
+    assert_eq!(<[mint::Vector3<f32>]>::EL_PADDING, 4);
+}
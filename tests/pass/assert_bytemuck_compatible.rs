@@ -0,0 +1,8 @@
+use encase::ShaderType;
+
+fn main() {
+    u32::assert_bytemuck_compatible();
+    <[f32; 8]>::assert_bytemuck_compatible();
+    <[mint::Vector4<f32>; 4]>::assert_bytemuck_compatible();
+    mint::ColumnMatrix4::<f32>::assert_bytemuck_compatible();
+}
@@ -0,0 +1,18 @@
+use encase::internal::{CreateFrom, ReadFrom, WriteInto};
+use encase::ShaderType;
+
+fn main() {
+    check::<[u32; 4]>();
+    check::<Vec<f32>>();
+    check_write_only::<[u32]>();
+}
+
+fn check<T: ShaderType + WriteInto + ReadFrom + CreateFrom>() {
+    T::assert_can_write_into();
+    T::assert_can_read_from();
+    T::assert_can_create_from();
+}
+
+fn check_write_only<T: ?Sized + ShaderType + WriteInto>() {
+    T::assert_can_write_into();
+}
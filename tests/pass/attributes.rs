@@ -1,3 +1,5 @@
+use core::marker::PhantomData;
+
 use encase::ShaderType;
 
 fn main() {}
@@ -24,3 +26,23 @@ struct TestDocComment {
     /// This is an unsigned integer
     a: u32,
 }
+
+#[derive(ShaderType)]
+struct TestGroupedAttributes {
+    #[shader(align(16), size(8))]
+    a: u32,
+    #[shader(size(8))]
+    #[align(16)]
+    b: u32,
+    #[shader(size(runtime))]
+    c: Vec<u32>,
+}
+
+#[derive(ShaderType)]
+struct TestSkippedAfterRtArray<T> {
+    a: u32,
+    #[size(runtime)]
+    data: Vec<u32>,
+    #[shader(skip)]
+    _m: PhantomData<T>,
+}
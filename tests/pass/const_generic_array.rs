@@ -0,0 +1,29 @@
+use encase::{ShaderSize, ShaderType, StorageBuffer};
+
+#[derive(ShaderType, Debug, PartialEq)]
+struct Lights<const N: usize> {
+    count: u32,
+    positions: [mint::Vector3<f32>; N],
+    intensities: [f32; N],
+}
+
+fn main() {
+    assert_eq!(<Lights<1>>::SHADER_SIZE.get(), 48);
+    assert_eq!(<Lights<3>>::SHADER_SIZE.get(), 80);
+
+    let lights = Lights::<2> {
+        count: 2,
+        positions: [
+            mint::Vector3::from([1.0, 2.0, 3.0]),
+            mint::Vector3::from([4.0, 5.0, 6.0]),
+        ],
+        intensities: [0.5, 1.0],
+    };
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&lights).unwrap();
+    assert_eq!(buffer.as_ref().len(), 64);
+
+    let created: Lights<2> = buffer.create().unwrap();
+    assert_eq!(created, lights);
+}
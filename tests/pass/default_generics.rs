@@ -0,0 +1,32 @@
+use encase::{ShaderType, StorageBuffer};
+
+#[derive(ShaderType, Debug, PartialEq)]
+struct Grid<T = f32> {
+    data: [T; 4],
+}
+
+#[derive(ShaderType, Debug, PartialEq)]
+struct WithSize<T = u32, const N: usize = 2> {
+    a: [T; N],
+    #[size(16)]
+    b: T,
+}
+
+fn main() {
+    let grid: Grid = Grid {
+        data: [1.0, 2.0, 3.0, 4.0],
+    };
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&grid).unwrap();
+    let created: Grid = buffer.create().unwrap();
+    assert_eq!(created, grid);
+
+    let sized: WithSize = WithSize { a: [1, 2], b: 3 };
+    assert_eq!(<WithSize>::min_size().get(), 24);
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&sized).unwrap();
+    let created: WithSize = buffer.create().unwrap();
+    assert_eq!(created, sized);
+}
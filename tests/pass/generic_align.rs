@@ -0,0 +1,35 @@
+use encase::{ShaderSize, ShaderType, StorageBuffer};
+
+#[derive(ShaderType, Debug, PartialEq)]
+struct Wrap<T> {
+    #[align(16)]
+    x: T,
+}
+
+#[derive(ShaderType, Debug, PartialEq)]
+struct Pair<T, const N: usize> {
+    a: u32,
+    #[align(16)]
+    b: [T; N],
+    #[size(16)]
+    c: T,
+}
+
+fn main() {
+    assert_eq!(<Wrap<f32>>::METADATA.alignment().get(), 16);
+    assert_eq!(<Wrap<f32>>::SHADER_SIZE.get(), 16);
+    assert_eq!(<Wrap<mint::Vector2<f32>>>::SHADER_SIZE.get(), 16);
+
+    assert_eq!(<Pair<u32, 2>>::METADATA.offset(1), 16);
+    assert_eq!(<Pair<u32, 2>>::SHADER_SIZE.get(), 48);
+
+    let value = Wrap { x: 1.5f32 };
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&value).unwrap();
+    assert_eq!(buffer.create::<Wrap<f32>>().unwrap(), value);
+
+    let value = Pair::<u32, 2> { a: 1, b: [2, 3], c: 4 };
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&value).unwrap();
+    assert_eq!(buffer.create::<Pair<u32, 2>>().unwrap(), value);
+}
@@ -5,7 +5,7 @@ use futures::executor::block_on;
 use mint::{Vector2, Vector3};
 use wgpu::{include_wgsl, util::DeviceExt};
 
-#[derive(Debug, ShaderType, PartialEq)]
+#[derive(Debug, Clone, Copy, ShaderType, PartialEq)]
 struct A {
     u: u32,
     v: u32,
@@ -152,6 +152,128 @@ fn array_length() {
     assert_eq!(in_value, out_val);
 }
 
+#[test]
+fn type_layout() {
+    use encase::{TypeLayout, TypeLayoutKind};
+
+    fn fields(layout: &TypeLayout) -> Vec<(&'static str, u64, u64)> {
+        match &layout.kind {
+            TypeLayoutKind::Struct { fields } => fields
+                .iter()
+                .map(|field| (field.name, field.offset, field.padding))
+                .collect(),
+            kind => panic!("expected a struct, got {kind:?}"),
+        }
+    }
+
+    let layout = B::type_layout();
+    assert_eq!((layout.alignment, layout.size), (32, 320));
+    assert_eq!(
+        fields(&layout),
+        [
+            ("a", 0, 8),
+            ("b", 16, 0),
+            ("c", 28, 0),
+            ("d", 32, 12),
+            ("e", 48, 8),
+            ("f", 96, 4),
+            ("g", 112, 0),
+            ("h", 232, 20),
+            ("i", 256, 0),
+        ]
+    );
+
+    let TypeLayoutKind::Struct { fields: b_fields } = layout.kind else {
+        unreachable!()
+    };
+
+    assert_eq!(
+        b_fields[1].layout,
+        TypeLayout {
+            alignment: 16,
+            size: 12,
+            kind: TypeLayoutKind::Vector { len: 3 },
+        }
+    );
+    assert_eq!(
+        b_fields[7].layout,
+        TypeLayout {
+            alignment: 4,
+            size: 4,
+            kind: TypeLayoutKind::Scalar,
+        }
+    );
+
+    let a_layout = A::type_layout();
+    assert_eq!((a_layout.alignment, a_layout.size), (8, 40));
+    assert_eq!(
+        fields(&a_layout),
+        [
+            ("u", 0, 0),
+            ("v", 4, 0),
+            ("w", 8, 0),
+            ("x", 16, 12),
+            ("xx", 32, 4)
+        ]
+    );
+    assert_eq!(b_fields[4].layout, a_layout);
+
+    assert_eq!(
+        b_fields[6].layout.kind,
+        TypeLayoutKind::Array {
+            element: Box::new(a_layout.clone()),
+            stride: 40,
+            len: Some(3),
+        }
+    );
+    assert_eq!(
+        b_fields[8].layout.kind,
+        TypeLayoutKind::Array {
+            element: Box::new(a_layout),
+            stride: 40,
+            len: None,
+        }
+    );
+}
+
+#[test]
+fn read_field() {
+    let a = A {
+        u: 1,
+        v: 2,
+        w: Vector2 { x: 3, y: 4 },
+        x: 5,
+        xx: 6,
+    };
+    let b = B {
+        a: Vector2 { x: 7, y: 8 },
+        b: Vector3 { x: 9, y: 10, z: 11 },
+        c: 12,
+        d: 13,
+        e: a,
+        f: Vector3 {
+            x: 14,
+            y: 15,
+            z: 16,
+        },
+        g: [A { u: 17, ..a }, A { u: 18, ..a }, A { u: 19, ..a }],
+        h: -20,
+        i: vec![A { u: 21, ..a }; 2],
+    };
+
+    let mut buffer = StorageBuffer::new(Vec::<u8>::new());
+    buffer.write(&b).unwrap();
+
+    let offset = B::METADATA.offset(2);
+    assert_eq!(offset, 28);
+    assert_eq!(buffer.read_field::<u32>(offset).unwrap(), 12);
+
+    let e: A = buffer.read_field(B::METADATA.offset(4)).unwrap();
+    assert_eq!(e, a);
+    let h: i32 = buffer.read_field(B::METADATA.offset(7)).unwrap();
+    assert_eq!(h, -20);
+}
+
 fn in_out<IN: encase::ShaderType, OUT: encase::ShaderType>(
     shader: wgpu::ShaderModuleDescriptor,
     data: &[u8],